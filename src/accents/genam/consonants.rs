@@ -0,0 +1,165 @@
+//! GenAm Consonants
+//!
+//! Each GenAm consonant is available here as a function that builds
+//! its phoneme directly, without the string hashing of
+//! [phoneme](super::phoneme). Functions are named with lowercase ARPABET
+//! symbols, so that every name is plain ASCII.
+
+use crate::builders::consonants::*;
+use crate::builders::{rhotic, SegmentBuilder};
+use crate::features::Segment;
+use crate::phoneme::Phoneme;
+use crate::phoneme::Phoneme::*;
+
+/// m, a voiced bilabial nasal
+pub fn m() -> Phoneme {
+    Monosegment(consonant(&[vd, bilabial, nasal], 'm'))
+}
+
+/// n, a voiced alveolar nasal
+pub fn n() -> Phoneme {
+    Monosegment(consonant(&[vd, alveolar, nasal], 'n'))
+}
+
+/// ŋ, a voiced velar nasal
+pub fn ng() -> Phoneme {
+    Monosegment(consonant(&[vd, velar, nasal], 'ŋ'))
+}
+
+/// p, a voiceless bilabial stop
+pub fn p() -> Phoneme {
+    Monosegment(consonant(&[vl, bilabial, stop], 'p'))
+}
+
+/// b, a voiced bilabial stop
+pub fn b() -> Phoneme {
+    Monosegment(consonant(&[vd, bilabial, stop], 'b'))
+}
+
+/// t, a voiceless alveolar stop
+pub fn t() -> Phoneme {
+    Monosegment(consonant(&[vl, alveolar, stop], 't'))
+}
+
+/// d, a voiced alveolar stop
+pub fn d() -> Phoneme {
+    Monosegment(consonant(&[vd, alveolar, stop], 'd'))
+}
+
+/// k, a voiceless velar stop
+pub fn k() -> Phoneme {
+    Monosegment(consonant(&[vl, velar, stop], 'k'))
+}
+
+/// ɡ, a voiced velar stop
+pub fn g() -> Phoneme {
+    Monosegment(consonant(&[vd, velar, stop], 'ɡ'))
+}
+
+/// t͡ʃ, a voiceless postalveolar affricate
+pub fn ch() -> Phoneme {
+    Disegment(
+        consonant(&[vl, alveolar, stop], 't'),
+        consonant(&[vl, postalveolar, distrib, sibilant, fricative], 'ʃ'),
+    )
+}
+
+/// d͡ʒ, a voiced postalveolar affricate
+pub fn jh() -> Phoneme {
+    Disegment(
+        consonant(&[vd, alveolar, stop], 'd'),
+        consonant(&[vd, postalveolar, distrib, sibilant, fricative], 'ʒ'),
+    )
+}
+
+/// f, a voiceless labiodental fricative
+pub fn f() -> Phoneme {
+    Monosegment(consonant(&[vl, labiodental, sibilant, fricative], 'f'))
+}
+
+/// v, a voiced labiodental fricative
+pub fn v() -> Phoneme {
+    Monosegment(consonant(&[vd, labiodental, sibilant, fricative], 'v'))
+}
+
+/// θ, a voiceless dental fricative
+pub fn th() -> Phoneme {
+    Monosegment(consonant(&[vl, dental, distrib, fricative], 'θ'))
+}
+
+/// ð, a voiced dental fricative
+pub fn dh() -> Phoneme {
+    Monosegment(consonant(&[vd, dental, distrib, fricative], 'ð'))
+}
+
+/// s, a voiceless alveolar fricative
+pub fn s() -> Phoneme {
+    Monosegment(consonant(&[vl, alveolar, sibilant, fricative], 's'))
+}
+
+/// z, a voiced alveolar fricative
+pub fn z() -> Phoneme {
+    Monosegment(consonant(&[vd, alveolar, sibilant, fricative], 'z'))
+}
+
+/// ʃ, a voiceless postalveolar fricative
+pub fn sh() -> Phoneme {
+    Monosegment(consonant(
+        &[vl, postalveolar, distrib, sibilant, fricative],
+        'ʃ',
+    ))
+}
+
+/// ʒ, a voiced postalveolar fricative
+pub fn zh() -> Phoneme {
+    Monosegment(consonant(
+        &[vd, postalveolar, distrib, sibilant, fricative],
+        'ʒ',
+    ))
+}
+
+/// h, a voiceless glottal fricative
+pub fn hh() -> Phoneme {
+    Monosegment(consonant(&[vl, glottal, fricative], 'h'))
+}
+
+/// ʔ, a voiceless glottal stop. This occurs in GenAm only as an allophone,
+/// such as of 't' before a syllabic 'n̩' ("button").
+pub fn q() -> Phoneme {
+    Monosegment(consonant(&[vl, glottal_stop], 'ʔ'))
+}
+
+/// l, a voiced alveolar lateral approximant
+pub fn l() -> Phoneme {
+    Monosegment(consonant(
+        &[vd, alveolar, lateral, distrib, approximant],
+        'l',
+    ))
+}
+
+/// ɹ, a voiced alveolar approximant
+pub fn r() -> Phoneme {
+    Monosegment(consonant(
+        &[vd, alveolar, distrib, rhotic, approximant],
+        'ɹ',
+    ))
+}
+
+/// j, a voiced palatal glide
+pub fn y() -> Phoneme {
+    Monosegment(consonant(&[vd, palatal, glide], 'j'))
+}
+
+/// ʍ, a voiceless labial-velar glide
+pub fn wh() -> Phoneme {
+    Monosegment(consonant(&[vl, bilabial, velar, glide], 'ʍ'))
+}
+
+/// w, a voiced labial-velar glide
+pub fn w() -> Phoneme {
+    Monosegment(consonant(&[vd, bilabial, velar, glide], 'w'))
+}
+
+fn consonant(fs: &[fn(&mut Segment)], sym: char) -> Segment {
+    SegmentBuilder::consonant(fs, sym)
+}
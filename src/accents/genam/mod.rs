@@ -5,12 +5,21 @@
 //!
 //! [General American English]: <https://en.wikipedia.org/wiki/General_American_English>
 
-use crate::builders::words::{from_accent, WordConstructorError};
+use crate::builders::consonants::vd;
+use crate::builders::words::{from_accent, tokenize_ipa, WordConstructorError};
+use crate::feature_classes::{is_lateral, is_stop, is_voiceless, is_vowel};
+use crate::features::*;
 use crate::phoneme::Phoneme;
+use crate::stress::BinaryStress;
+use crate::syllable::Syllable;
+use crate::tone::Tone;
 use crate::word::Word;
+use lazy_static::lazy_static;
 use std::collections::HashSet;
 
+pub mod consonants;
 mod sounds;
+pub mod vowels;
 
 /// phoneme provides a constructor for General American English phonemes.
 /// Given the IPA symbol for a phoneme, return the associated phoneme
@@ -32,7 +41,63 @@ mod sounds;
 /// )
 /// ```
 pub fn phoneme(symbol: &str) -> Option<Phoneme> {
-    sounds::SOUNDS.get(symbol).cloned()
+    // canonical symbols are found without normalizing (and allocating)
+    sounds::SOUNDS
+        .get(symbol)
+        .or_else(|| sounds::SOUNDS.get(normalize_symbol(symbol).as_str()))
+        .cloned()
+}
+
+/// normalize_symbol maps common alternative encodings of an IPA symbol to the
+/// canonical form used by the GenAm accent, so that [phoneme] accepts them.
+///
+/// The following alternatives are recognized:
+/// - lookalike letters: Latin "g" (U+0067) for "ɡ" (U+0261) and "r" for "ɹ"
+/// - ligature affricates: "ʧ" for "t͡ʃ" and "ʤ" for "d͡ʒ"
+/// - precomposed rhotic vowels: "ɚ" for "ə˞" and "ɝ" for "ɜ˞"
+/// - tie bars: a two-part symbol written without a tie bar ("tʃ"), with the
+///   lower tie bar U+035C ("t͜ʃ"), or with the tie bar out of place is given
+///   the tie bar U+0361 between its two parts ("t͡ʃ")
+///
+/// Symbols that are not recognized are returned with only the letter
+/// substitutions applied.
+///
+/// # Examples
+///
+/// ```
+/// # use sound::accents::genam::normalize_symbol;
+///
+/// assert_eq!(normalize_symbol("g"), "ɡ");
+/// assert_eq!(normalize_symbol("tʃ"), "t͡ʃ");
+/// ```
+pub fn normalize_symbol(s: &str) -> String {
+    let mut base = String::new();
+    for c in s.chars() {
+        match c {
+            'g' => base.push('ɡ'),
+            'r' => base.push('ɹ'),
+            'ʧ' => base.push_str("tʃ"),
+            'ʤ' => base.push_str("dʒ"),
+            'ɚ' => base.push_str("ə˞"),
+            'ɝ' => base.push_str("ɜ˞"),
+            '\u{0361}' | '\u{035C}' => {}
+            _ => base.push(c),
+        }
+    }
+
+    if sounds::SOUNDS.contains_key(base.as_str()) {
+        return base;
+    }
+
+    let mut chars = base.chars();
+    if let (Some(first), rest) = (chars.next(), chars.as_str()) {
+        let tied = format!("{}\u{0361}{}", first, rest);
+        if sounds::SOUNDS.contains_key(tied.as_str()) {
+            return tied;
+        }
+    }
+
+    base
 }
 
 /// The set of IPA symbols that comprise the GenAm accent
@@ -40,11 +105,263 @@ pub fn symbols() -> HashSet<&'static str> {
     sounds::SOUNDS.keys().copied().collect()
 }
 
+/// The set of IPA symbols that comprise the GenAm accent, together with the
+/// common alternative spellings that [normalize_symbol] accepts for them.
+///
+/// This includes lookalike ASCII letters ("g", "r"), ligatures ("ʧ", "ʤ"),
+/// precomposed rhotic vowels ("ɚ", "ɝ"), and two-part symbols written without
+/// a tie bar ("tʃ", "aɪ"). Use this set to validate real-world input that has
+/// not been normalized; use [symbols] for the canonical spellings only.
+pub fn symbols_with_aliases() -> HashSet<&'static str> {
+    let mut set = symbols();
+    set.extend(ALIASES);
+    set
+}
+
+/// The GenAm vowel symbols, in a linguistically grouped order: monophthongs
+/// (front, central, then back, each from high to low), then diphthongs, then
+/// rhotic vowels. Together with [CONSONANTS], this lists every symbol in
+/// [symbols] exactly once, for display in a fixed order.
+pub const VOWELS: &[&str] = &[
+    "i", "ɪ", "ɛ", "æ", "ə", "ʌ", "u", "ʊ", "ɔ", "ɑ", "e͡ɪ", "a͡ɪ", "a͡ʊ", "o͡ʊ",
+    "ɔ͡ɪ", "ɜ˞", "ə˞",
+];
+
+/// The GenAm consonant symbols, in a linguistically grouped order: stops,
+/// affricates, fricatives, nasals, liquids, then glides, each from the front
+/// of the mouth to the back and voiceless before voiced. See [VOWELS].
+pub const CONSONANTS: &[&str] = &[
+    "p", "b", "t", "d", "k", "ɡ", "ʔ", "t͡ʃ", "d͡ʒ", "f", "v", "θ", "ð", "s",
+    "z", "ʃ", "ʒ", "h", "m", "n", "ŋ", "l", "ɹ", "ʍ", "w", "j",
+];
+
+// Alternative spellings of GenAm symbols, all accepted by normalize_symbol
+const ALIASES: &[&str] = &[
+    "g", "r", "ʧ", "ʤ", "ɚ", "ɝ", "tʃ", "dʒ", "eɪ", "aɪ", "aʊ", "oʊ", "ɔɪ",
+];
+
+/// The symbol to phoneme entries of the GenAm accent, sorted by symbol. These
+/// can be combined with another accent's entries using
+/// [merge](crate::accents::merge).
+pub fn entries() -> Vec<(&'static str, Phoneme)> {
+    let mut entries: Vec<(&'static str, Phoneme)> =
+        sounds::SOUNDS.iter().map(|(sym, p)| (*sym, *p)).collect();
+    entries.sort_by_key(|(sym, _)| *sym);
+    entries
+}
+
 /// The set of Phonemes that comprise the GenAm accent
 pub fn phonemes() -> HashSet<&'static Phoneme> {
     sounds::SOUNDS.values().collect()
 }
 
+/// contains tests whether a phoneme is in the GenAm accent, comparing by
+/// features only (see [Phoneme::feature_eq]). A phoneme built with a
+/// different symbol than GenAm uses, or with builders applied in a different
+/// order, is still contained if its features match.
+///
+/// # Examples
+///
+/// ```
+/// # use sound::accents::genam::contains;
+/// # use sound::builders::SegmentBuilder;
+/// # use sound::builders::consonants::*;
+/// # use sound::phoneme::Phoneme::Monosegment;
+///
+/// let g = Monosegment(SegmentBuilder::consonant(&[vd, velar, stop], 'g'));
+/// assert!(contains(&g));
+/// ```
+pub fn contains(p: &Phoneme) -> bool {
+    sounds::SOUNDS.values().any(|q| q.feature_eq(p))
+}
+
+/// symbol_for provides the GenAm symbol of the phoneme that has the same
+/// features as p (see [Phoneme::feature_eq]), if there is one.
+///
+/// This is the symbol that [phoneme] looks the phoneme up by, which is not
+/// always the symbol stored on its segments ("ɜ˞" rather than 'ɝ'). If more
+/// than one GenAm phoneme has the same features, the first of their symbols
+/// in sorted order is given, so the result is always the same.
+///
+/// # Examples
+///
+/// ```
+/// # use sound::accents::genam::symbol_for;
+/// # use sound::builders::SegmentBuilder;
+/// # use sound::builders::consonants::*;
+/// # use sound::phoneme::Phoneme::Monosegment;
+///
+/// let g = Monosegment(SegmentBuilder::consonant(&[vd, velar, stop], 'g'));
+/// assert_eq!(symbol_for(&g), Some("ɡ"));
+/// ```
+pub fn symbol_for(p: &Phoneme) -> Option<&'static str> {
+    sounds::SOUNDS
+        .iter()
+        .filter(|(_, q)| q.feature_eq(p))
+        .map(|(sym, _)| *sym)
+        .min()
+}
+
+/// The set of Phonemes in the GenAm accent that satisfy a predicate, such as
+/// one of the natural class tests in [feature_classes](crate::feature_classes).
+///
+/// # Examples
+///
+/// ```
+/// # use sound::accents::genam::{phoneme, phonemes_matching};
+/// # use sound::feature_classes::is_nasal;
+///
+/// let nasals = phonemes_matching(is_nasal);
+///
+/// assert_eq!(nasals.len(), 3);
+/// assert!(nasals.contains(&phoneme("ŋ").unwrap()));
+/// ```
+pub fn phonemes_matching(
+    pred: fn(Phoneme) -> bool,
+) -> HashSet<&'static Phoneme> {
+    sounds::SOUNDS.values().filter(|p| pred(**p)).collect()
+}
+
+/// from_arpabet provides the GenAm phoneme for an ARPAbet phone, as used in
+/// the CMU Pronouncing Dictionary ("AA" -> "ɑ", "CH" -> "t͡ʃ").
+///
+/// The 39 phones of the CMU dictionary are recognized, in upper or lower
+/// case. A trailing stress digit ("AA1") is not part of the phone and is not
+/// accepted here; see [from_arpabet](crate::builders::words::from_arpabet)
+/// for reading whole transcriptions.
+///
+/// # Examples
+///
+/// ```
+/// # use sound::accents::genam::{from_arpabet, phoneme};
+///
+/// assert_eq!(from_arpabet("CH"), phoneme("t͡ʃ"));
+/// assert_eq!(from_arpabet("X"), None);
+/// ```
+pub fn from_arpabet(code: &str) -> Option<Phoneme> {
+    let code = code.to_ascii_uppercase();
+    ARPABET
+        .iter()
+        .find(|(arpa, _)| *arpa == code)
+        .and_then(|(_, ipa)| phoneme(ipa[0]))
+}
+
+/// to_arpabet provides the ARPAbet phone for a GenAm phoneme, the inverse of
+/// [from_arpabet].
+///
+/// Some phones stand for more than one GenAm phoneme: ARPAbet writes the
+/// reduced vowels "ə" and "ə˞" as unstressed "AH" and "ER", and has no
+/// separate phone for "ʍ", which is written "W". Phonemes with no ARPAbet
+/// phone ("ʔ") give None.
+pub fn to_arpabet(p: &Phoneme) -> Option<&'static str> {
+    ARPABET
+        .iter()
+        .find(|(_, ipa)| ipa.iter().any(|s| phoneme(s).as_ref() == Some(p)))
+        .map(|(arpa, _)| *arpa)
+}
+
+lazy_static! {
+    // The 39 phones of the CMU Pronouncing Dictionary, each with the GenAm
+    // IPA symbols it stands for, read from data/arpabetMap.csv. The first
+    // symbol listed for a phone is the one given by from_arpabet.
+    static ref ARPABET: Vec<(&'static str, Vec<&'static str>)> =
+        include_str!("../../../data/arpabetMap.csv")
+            .lines()
+            .skip(1)
+            .filter_map(|line| line.split_once(','))
+            .map(|(arpa, ipa)| (arpa.trim(), ipa.split_whitespace().collect()))
+            .collect();
+}
+
+/// allophone selects the contextual realization (allophone) of a GenAm
+/// phoneme, given the phonemes immediately to its left and right (None at a
+/// word boundary), and the syllable whose onset it begins (None when it does
+/// not begin a syllable).
+///
+/// The following rules are applied in order, matching on features rather
+/// than symbols, so that phonemes built from features are also realized:
+/// - flapping: an alveolar stop ("t" or "d") between two vowels that begins
+///   an unstressed syllable becomes the flap 'ɾ' ("butter" \[ˈbʌɾɚ\]). A
+///   syllable with no stress information (a single syllable word) is not
+///   unstressed, and a stop written in a coda ("ˈbʌt.ɚ") is not flapped.
+/// - aspiration: a voiceless oral stop (not 'ʔ') that begins a syllable and
+///   comes before a vowel gains \[spread glottis\] ("pin" \[pʰɪn\], "attack"
+///   \[əˈtʰæk\]), but not after "s" in the same onset ("spin"). The symbol is
+///   unchanged, since aspiration cannot be written as a single symbol.
+/// - velarization: a lateral after a vowel and not before one (in a coda)
+///   becomes the velarized 'ɫ' ("feel" \[fiɫ\]), gaining a \[+back\] dorsal
+///   node.
+///
+/// When no rule applies, the phoneme is returned unchanged.
+///
+/// ```
+/// # use sound::accents::genam::{allophone, word};
+/// let butter = word("ˈbʌ.tɚ").unwrap();
+/// let syllables: Vec<_> = butter.iter().collect();
+/// let (first, second) = (syllables[0], syllables[1]);
+/// let t = allophone(
+///     second.onset[0],
+///     Some(first.nucleus),
+///     Some(second.nucleus),
+///     Some(second),
+/// );
+/// assert_eq!(t.symbol(), "ɾ");
+/// ```
+pub fn allophone(
+    p: Phoneme,
+    left: Option<Phoneme>,
+    right: Option<Phoneme>,
+    onset_of: Option<&Syllable>,
+) -> Phoneme {
+    let after_vowel = left.is_some_and(is_vowel);
+    let before_vowel = right.is_some_and(is_vowel);
+    let unstressed = onset_of
+        .and_then(|syl| syl.stress)
+        .map(|s| s.to_binary_stress())
+        == Some(BinaryStress::Unstressed);
+    let mut seg = match p {
+        Phoneme::Monosegment(seg) => seg,
+        Phoneme::Disegment(..) => return p,
+    };
+    let oral = seg.autosegmental_features.place.is_some();
+    let alveolar = accessors::get_anterior(&seg) == Some(BinaryFeature::Marked);
+
+    if is_stop(p) && alveolar && after_vowel && before_vowel && unstressed {
+        flap(&mut seg)
+    } else if is_stop(p)
+        && oral
+        && is_voiceless(p)
+        && onset_of.is_some()
+        && before_vowel
+    {
+        aspirated(&mut seg)
+    } else if is_lateral(p) && after_vowel && !before_vowel {
+        velarized(&mut seg)
+    } else {
+        return p;
+    }
+
+    seg.into()
+}
+
+fn aspirated(s: &mut Segment) {
+    s.autosegmental_features
+        .laryngeal
+        .get_or_insert(LaryngealFeatures::default())
+        .spread_glottis = Some(UnaryFeature::Marked);
+}
+
+fn flap(s: &mut Segment) {
+    vd(s);
+    s.root_features.sonorant = BinaryFeature::Marked;
+    s.symbol = 'ɾ';
+}
+
+fn velarized(s: &mut Segment) {
+    crate::builders::consonants::velarized(s);
+    s.symbol = 'ɫ';
+}
+
 /// word provides a constructor for syllable-structured groups of General American English
 /// phonemes. Given a collection of IPA symbols for the sounds of the word, return either a Word
 /// comprised of those phonemes or a [WordConstructorError].
@@ -75,11 +392,51 @@ pub fn word(word_desc: &str) -> Result<Word, WordConstructorError> {
     from_accent(phoneme, word_desc)
 }
 
+/// unrecognized_tokens lists every symbol in a word description that is not
+/// in the GenAm inventory, in order. Unlike [word], which fails on the first
+/// unknown symbol, this reports every problem in an input at once.
+///
+/// The description is split with
+/// [tokenize_ipa](crate::builders::words::tokenize_ipa), and stress marks
+/// (ˈ, ˌ, and the digits 1-4), syllable breaks (.), and tone marks (see
+/// [Tone::is_tone_symbol]) are ignored, as they are in [word]. Symbols are
+/// looked up with [phoneme], so alternative spellings are recognized, and as
+/// in [word], two adjacent symbols written without a tie bar ("aɪ") are
+/// recognized when they spell a GenAm phoneme together.
+///
+/// ```
+/// # use sound::accents::genam::unrecognized_tokens;
+/// assert_eq!(unrecognized_tokens("ˈbɑ.xəq"), vec!["x", "q"]);
+/// ```
+pub fn unrecognized_tokens(s: &str) -> Vec<String> {
+    let tokens: Vec<String> = tokenize_ipa(s)
+        .into_iter()
+        .filter(|t| {
+            !matches!(t.as_str(), "ˈ" | "ˌ" | "." | "1" | "2" | "3" | "4")
+                && !t.chars().all(Tone::is_tone_symbol)
+        })
+        .collect();
+    let mut unrecognized = Vec::new();
+    let mut rest = tokens.iter().peekable();
+    while let Some(token) = rest.next() {
+        if phoneme(token).is_some() {
+            continue;
+        }
+        match rest.peek() {
+            Some(next) if phoneme(&format!("{}{}", token, next)).is_some() => {
+                rest.next();
+            }
+            _ => unrecognized.push(token.clone()),
+        }
+    }
+    unrecognized
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    use crate::features::*;
+    use crate::feature_classes;
     use std::iter::FromIterator;
 
     #[test]
@@ -88,11 +445,287 @@ mod tests {
             symbols(),
             HashSet::from_iter(vec![
                 "m", "n", "ŋ", "p", "b", "t", "d", "k", "ɡ", "t͡ʃ", "d͡ʒ", "f",
-                "v", "θ", "ð", "s", "z", "ʃ", "ʒ", "h", "l", "ɹ", "j", "ʍ",
-                "w", "i", "ɪ", "ɛ", "ə", "æ", "ʌ", "ɑ", "u", "ʊ", "ɔ", "e͡ɪ",
-                "a͡ɪ", "a͡ʊ", "o͡ʊ", "ɔ͡ɪ", "ɜ˞", "ə˞"
+                "v", "θ", "ð", "s", "z", "ʃ", "ʒ", "h", "ʔ", "l", "ɹ", "j",
+                "ʍ", "w", "i", "ɪ", "ɛ", "ə", "æ", "ʌ", "ɑ", "u", "ʊ", "ɔ",
+                "e͡ɪ", "a͡ɪ", "a͡ʊ", "o͡ʊ", "ɔ͡ɪ", "ɜ˞", "ə˞"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_vowels_and_consonants() {
+        let inventory = symbols();
+        let listed: Vec<&str> =
+            VOWELS.iter().chain(CONSONANTS).copied().collect();
+        assert_eq!(listed.len(), inventory.len());
+        assert_eq!(HashSet::from_iter(listed), inventory);
+        assert!(VOWELS.iter().all(|v| is_vowel(phoneme(v).unwrap())));
+        assert!(CONSONANTS.iter().all(|c| !is_vowel(phoneme(c).unwrap())));
+    }
+
+    #[test]
+    fn test_symbols_with_aliases() {
+        let aliased = symbols_with_aliases();
+        assert!(aliased.contains("g"));
+        assert!(!symbols().contains("g"));
+        assert!(symbols().is_subset(&aliased));
+        for alias in ALIASES {
+            assert!(symbols().contains(normalize_symbol(alias).as_str()));
+        }
+    }
+
+    #[test]
+    fn test_phonemes_matching() {
+        let nasals = phonemes_matching(feature_classes::is_nasal);
+        assert_eq!(
+            nasals,
+            HashSet::from_iter(vec![
+                &phoneme("m").unwrap(),
+                &phoneme("n").unwrap(),
+                &phoneme("ŋ").unwrap(),
             ])
         );
+        // 10 monophthongs, 5 diphthongs, 2 rhotic vowels
+        assert_eq!(phonemes_matching(feature_classes::is_vowel).len(), 17);
+    }
+
+    fn phon(s: &str) -> Phoneme {
+        phoneme(s).unwrap()
+    }
+
+    #[test]
+    fn test_accessors() {
+        assert_eq!(consonants::t(), phon("t"));
+        assert_eq!(consonants::ch(), phon("t͡ʃ"));
+        assert_eq!(consonants::r(), phon("ɹ"));
+        assert_eq!(vowels::iy(), phon("i"));
+        assert_eq!(vowels::ow(), phon("o͡ʊ"));
+        assert_eq!(vowels::axr(), phon("ə˞"));
+    }
+
+    #[test]
+    fn test_arpabet() {
+        assert_eq!(ARPABET.len(), 39);
+        for (arpa, ipa) in ARPABET.iter() {
+            assert_eq!(from_arpabet(arpa), Some(phon(ipa[0])));
+            for s in ipa {
+                assert_eq!(to_arpabet(&phon(s)), Some(*arpa));
+            }
+        }
+        assert_eq!(from_arpabet("aa"), Some(phon("ɑ")));
+        assert_eq!(from_arpabet("AH"), Some(phon("ʌ")));
+        assert_eq!(to_arpabet(&phon("ə")), Some("AH"));
+        assert_eq!(to_arpabet(&phon("ʍ")), Some("W"));
+        assert_eq!(to_arpabet(&phon("ʔ")), None);
+        assert_eq!(from_arpabet("AA1"), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        use crate::builders::consonants::*;
+        use crate::builders::SegmentBuilder;
+
+        let p = Phoneme::Monosegment(SegmentBuilder::consonant(
+            &[stop, bilabial, vl],
+            'p',
+        ));
+        assert!(contains(&p));
+        let capital_p = Phoneme::Monosegment(SegmentBuilder::consonant(
+            &[vl, bilabial, stop],
+            'P',
+        ));
+        assert!(contains(&capital_p));
+        let x = Phoneme::Monosegment(SegmentBuilder::consonant(
+            &[vl, velar, fricative],
+            'x',
+        ));
+        assert!(!contains(&x));
+        assert!(phonemes().iter().all(|q| contains(q)));
+    }
+
+    #[test]
+    fn test_symbol_for() {
+        for (sym, p) in entries() {
+            assert_eq!(symbol_for(&p), Some(sym));
+        }
+        assert_eq!(symbol_for(&phon("ɜ˞")), Some("ɜ˞"));
+        assert_eq!(symbol_for(&phon("t͡ʃ").split()[0]), Some("t"));
+        let x = Phoneme::Monosegment(crate::builders::SegmentBuilder::segment(
+            &[],
+            'x',
+        ));
+        assert_eq!(symbol_for(&x), None);
+    }
+
+    #[test]
+    fn test_open_mid_contrast() {
+        let eh = phon("ɛ");
+        let e = phon("e͡ɪ").split()[0];
+        assert!(!eh.feature_eq(&e));
+        assert!(!phon("ʌ").feature_eq(&phon("ɔ")));
+        assert!(!phon("æ").feature_eq(&phon("ə")));
+        let all = entries();
+        for (i, (_, p)) in all.iter().enumerate() {
+            assert!(all[i + 1..].iter().all(|(_, q)| !p.feature_eq(q)));
+        }
+    }
+
+    #[test]
+    fn test_glottal_stop() {
+        let q = phon("ʔ");
+        assert!(feature_classes::is_stop(q));
+        assert!(feature_classes::is_consonant(q));
+        match q {
+            Phoneme::Monosegment(seg) => {
+                assert_eq!(seg.autosegmental_features.place, None)
+            }
+            _ => panic!("expected a monosegment"),
+        }
+    }
+
+    #[test]
+    fn test_unrecognized_tokens() {
+        assert_eq!(
+            unrecognized_tokens("ˈxɛ.lo͡ʊ.bɑq"),
+            vec![String::from("x"), String::from("q")]
+        );
+        assert_eq!(
+            unrecognized_tokens("ˈbaɪ.tʃən.ˌgo͜ʊ3ɪŋ"),
+            Vec::<String>::new()
+        );
+        assert_eq!(unrecognized_tokens("ɬa.ʀa"), vec!["ɬ", "a", "ʀ", "a"]);
+        assert!(unrecognized_tokens("").is_empty());
+    }
+
+    #[test]
+    fn test_unrecognized_tokens_skips_tone() {
+        assert!(unrecognized_tokens("ˈbɑ˧˥.lo͡ʊ˩").is_empty());
+        assert!(unrecognized_tokens("bɑ\u{0301}").is_empty());
+        assert_eq!(unrecognized_tokens("bɑ˧˥x"), vec!["x"]);
+    }
+
+    #[test]
+    fn test_normalize_symbol() {
+        assert_eq!(phoneme("g"), phoneme("ɡ"));
+        assert_eq!(phoneme("ʤ"), phoneme("d͡ʒ"));
+        assert_eq!(phoneme("tʃ"), phoneme("t͡ʃ"));
+        assert_eq!(phoneme("t͜ʃ"), phoneme("t͡ʃ"));
+        assert_eq!(phoneme("ɚ"), phoneme("ə˞"));
+        assert_eq!(phoneme("aɪ"), phoneme("a͡ɪ"));
+        assert!(phoneme("g").is_some());
+        assert_eq!(phoneme("x"), None);
+    }
+
+    // The syllables of a GenAm word description.
+    fn syls(desc: &str) -> Vec<Syllable> {
+        word(desc).unwrap().iter().cloned().collect()
+    }
+
+    #[test]
+    fn test_allophone_aspiration() {
+        let pin = syls("pɪn");
+        let p = allophone(phon("p"), None, Some(phon("ɪ")), Some(&pin[0]));
+        if let Phoneme::Monosegment(seg) = p {
+            assert_eq!(
+                accessors::get_spread_glottis(&seg),
+                Some(UnaryFeature::Marked)
+            );
+        } else {
+            panic!("expected monosegment")
+        }
+        // not syllable-initial (as in "spin")
+        assert_eq!(
+            allophone(phon("p"), Some(phon("s")), Some(phon("ɪ")), None),
+            phon("p")
+        );
+        // syllable-initial within a word (as in "apart")
+        let apart = syls("əˈpɑɹt");
+        let p = allophone(
+            phon("p"),
+            Some(phon("ə")),
+            Some(phon("ɑ")),
+            Some(&apart[1]),
+        );
+        assert_eq!(
+            accessors::get_spread_glottis(&p.first_segment()),
+            Some(UnaryFeature::Marked)
+        );
+        // voiced stops are not aspirated
+        assert_eq!(
+            allophone(phon("b"), None, Some(phon("ɪ")), Some(&pin[0])),
+            phon("b")
+        );
+    }
+
+    #[test]
+    fn test_allophone_attack_is_aspirated() {
+        let attack = syls("əˈtæk");
+        let t = allophone(
+            attack[1].onset[0],
+            Some(attack[0].nucleus),
+            Some(attack[1].nucleus),
+            Some(&attack[1]),
+        );
+        assert_eq!(t.symbol(), "t");
+        assert_eq!(
+            accessors::get_spread_glottis(&t.first_segment()),
+            Some(UnaryFeature::Marked)
+        );
+    }
+
+    #[test]
+    fn test_allophone_from_features() {
+        use crate::phoneme::from_desc;
+
+        let butter = syls("ˈbʌ.tɚ");
+        let t = from_desc("voiceless alveolar stop").unwrap();
+        let flapped =
+            allophone(t, Some(phon("ʌ")), Some(phon("ə˞")), Some(&butter[1]));
+        assert_eq!(flapped.symbol(), "ɾ");
+        let l = from_desc("voiced alveolar lateral approximant").unwrap();
+        assert_eq!(allophone(l, Some(phon("i")), None, None).symbol(), "ɫ");
+        let kin = syls("kɪn");
+        let k = from_desc("voiceless velar stop").unwrap();
+        assert_ne!(allophone(k, None, Some(phon("ɪ")), Some(&kin[0])), k);
+    }
+
+    #[test]
+    fn test_allophone_flapping() {
+        let butter = syls("ˈbʌ.tɚ");
+        let t = allophone(
+            butter[1].onset[0],
+            Some(butter[0].nucleus),
+            Some(butter[1].nucleus),
+            Some(&butter[1]),
+        );
+        assert_eq!(t.symbol(), "ɾ");
+        assert!(feature_classes::is_voiced(t));
+        assert_eq!(
+            allophone(phon("t"), Some(phon("ʌ")), None, None),
+            phon("t")
+        );
+        // "k" is not alveolar, and is not flapped
+        assert_ne!(
+            allophone(
+                phon("k"),
+                Some(phon("ʌ")),
+                Some(phon("ə˞")),
+                Some(&butter[1])
+            )
+            .symbol(),
+            "ɾ"
+        );
+    }
+
+    #[test]
+    fn test_allophone_velarization() {
+        let l = allophone(phon("l"), Some(phon("i")), None, None);
+        assert_eq!(l.symbol(), "ɫ");
+        let lee = syls("li");
+        assert_eq!(
+            allophone(phon("l"), None, Some(phon("i")), Some(&lee[0])),
+            phon("l")
+        );
     }
 
     #[test]
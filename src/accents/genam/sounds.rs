@@ -2,14 +2,8 @@
 //!
 //! This module contains the actual GenAm mappings from symbols to phonemes.
 
-use crate::builders::consonants::rhotic as rhotic_c;
-use crate::builders::consonants::*;
-use crate::builders::vowels::rhotic as rhotic_v;
-use crate::builders::vowels::*;
-use crate::builders::*;
-use crate::features::Segment;
+use super::{consonants, vowels};
 use crate::phoneme::Phoneme;
-use crate::phoneme::Phoneme::*;
 use std::collections::HashMap;
 
 use lazy_static::lazy_static;
@@ -18,64 +12,50 @@ use maplit::hashmap;
 lazy_static! {
     pub static ref SOUNDS: HashMap<&'static str, Phoneme> = hashmap! {
         //Consonants
-        "m"  => Monosegment(consonant(&[vd, bilabial, nasal], 'm')),
-        "n"  => Monosegment(consonant(&[vd, alveolar, nasal], 'n')),
-        "ŋ"  => Monosegment(consonant(&[vd, velar, nasal], 'ŋ')),
-        "p"  => Monosegment(consonant(&[vl, bilabial, stop], 'p')),
-        "b"  => Monosegment(consonant(&[vd, bilabial, stop], 'b')),
-        "t"  => Monosegment(consonant(&[vl, alveolar, stop], 't')),
-        "d"  => Monosegment(consonant(&[vd, alveolar, stop], 'd')),
-        "k"  => Monosegment(consonant(&[vl, velar, stop], 'k')),
-        "ɡ"  => Monosegment(consonant(&[vd, velar, stop], 'ɡ')),
-        "t͡ʃ" =>   Disegment(consonant(&[vl, alveolar, stop], 't'),
-                            consonant(&[vl, postalveolar, distrib, sibilant, fricative], 'ʃ')),
-        "d͡ʒ" =>   Disegment(consonant(&[vd, alveolar, stop], 'd'),
-                            consonant(&[vd, postalveolar, distrib, sibilant, fricative], 'ʒ')),
-        "f"  => Monosegment(consonant(&[vl, labiodental, sibilant, fricative], 'f')),
-        "v"  => Monosegment(consonant(&[vd, labiodental, sibilant, fricative], 'v')),
-        "θ"  => Monosegment(consonant(&[vl, dental, distrib, fricative], 'θ')),
-        "ð"  => Monosegment(consonant(&[vd, dental, distrib, fricative], 'ð')),
-        "s"  => Monosegment(consonant(&[vl, alveolar, sibilant, fricative], 's')),
-        "z"  => Monosegment(consonant(&[vd, alveolar, sibilant, fricative], 'z')),
-        "ʃ"  => Monosegment(consonant(&[vl, postalveolar, distrib, sibilant, fricative], 'ʃ')),
-        "ʒ"  => Monosegment(consonant(&[vd, postalveolar, distrib, sibilant, fricative], 'ʒ')),
-        "h"  => Monosegment(consonant(&[vl, glottal, fricative], 'h')),
-        "l"  => Monosegment(consonant(&[vd, alveolar, lateral, distrib, approximant], 'l')),
-        "ɹ"  => Monosegment(consonant(&[vd, alveolar, distrib, rhotic_c, approximant], 'ɹ')),
-        "j"  => Monosegment(consonant(&[vd, palatal, glide], 'j')),
-        "ʍ"  => Monosegment(consonant(&[vl, bilabial, velar, glide], 'ʍ')),
-        "w"  => Monosegment(consonant(&[vd, bilabial, velar, glide], 'w')),
+        "m" => consonants::m(),
+        "n" => consonants::n(),
+        "ŋ" => consonants::ng(),
+        "p" => consonants::p(),
+        "b" => consonants::b(),
+        "t" => consonants::t(),
+        "d" => consonants::d(),
+        "k" => consonants::k(),
+        "ɡ" => consonants::g(),
+        "t͡ʃ" => consonants::ch(),
+        "d͡ʒ" => consonants::jh(),
+        "f" => consonants::f(),
+        "v" => consonants::v(),
+        "θ" => consonants::th(),
+        "ð" => consonants::dh(),
+        "s" => consonants::s(),
+        "z" => consonants::z(),
+        "ʃ" => consonants::sh(),
+        "ʒ" => consonants::zh(),
+        "h" => consonants::hh(),
+        "ʔ" => consonants::q(),
+        "l" => consonants::l(),
+        "ɹ" => consonants::r(),
+        "j" => consonants::y(),
+        "ʍ" => consonants::wh(),
+        "w" => consonants::w(),
 
         // Vowels
-        "i"  => Monosegment(vowel(&[high, front, tense], 'i')),
-        "ɪ"  => Monosegment(vowel(&[high, front], 'ɪ')),
-        "ɛ"  => Monosegment(vowel(&[mid, front, tense], 'ɛ')),
-        "æ"  => Monosegment(vowel(&[mid, front], 'æ')),
-        "ə"  => Monosegment(vowel(&[mid, front], 'ə')),
-        "ʌ"  => Monosegment(vowel(&[mid, back], 'ʌ')),
-        "ɑ"  => Monosegment(vowel(&[low, back], 'ɑ')),
-        "u"  => Monosegment(vowel(&[high, back, rounded, tense], 'u')),
-        "ʊ"  => Monosegment(vowel(&[high, back, rounded], 'ʊ')),
-        "ɔ"  => Monosegment(vowel(&[mid, back, rounded], 'ɔ')),
-        "e͡ɪ" =>   Disegment(vowel(&[mid, front, tense], 'e'),
-                            vowel(&[high, front], 'ɪ')),
-        "a͡ɪ" =>   Disegment(vowel(&[low, front], 'a'),
-                            vowel(&[high, front], 'ɪ')),
-        "a͡ʊ" =>   Disegment(vowel(&[low, front], 'a'),
-                            vowel(&[high, back, rounded], 'ʊ')),
-        "o͡ʊ" =>   Disegment(vowel(&[mid, back, tense, rounded], 'o'),
-                            vowel(&[high, back, rounded], 'ʊ')),
-        "ɔ͡ɪ" =>   Disegment(vowel(&[mid, back, rounded], 'ɔ'),
-                            vowel(&[high, front], 'ɪ')),
-        "ɜ˞" => Monosegment(vowel(&[mid, front, tense, rhotic_v], 'ɝ')),
-        "ə˞" => Monosegment(vowel(&[mid, front, rhotic_v], 'ɚ')),
+        "i" => vowels::iy(),
+        "ɪ" => vowels::ih(),
+        "ɛ" => vowels::eh(),
+        "æ" => vowels::ae(),
+        "ə" => vowels::ax(),
+        "ʌ" => vowels::ah(),
+        "ɑ" => vowels::aa(),
+        "u" => vowels::uw(),
+        "ʊ" => vowels::uh(),
+        "ɔ" => vowels::ao(),
+        "e͡ɪ" => vowels::ey(),
+        "a͡ɪ" => vowels::ay(),
+        "a͡ʊ" => vowels::aw(),
+        "o͡ʊ" => vowels::ow(),
+        "ɔ͡ɪ" => vowels::oy(),
+        "ɜ˞" => vowels::er(),
+        "ə˞" => vowels::axr(),
     };
 }
-
-fn consonant(fs: &[fn(&mut Segment)], sym: char) -> Segment {
-    SegmentBuilder::consonant(fs, sym)
-}
-
-fn vowel(fs: &[fn(&mut Segment)], sym: char) -> Segment {
-    SegmentBuilder::vowel(fs, sym)
-}
@@ -0,0 +1,116 @@
+//! GenAm Vowels
+//!
+//! Each GenAm vowel is available here as a function that builds
+//! its phoneme directly, without the string hashing of
+//! [phoneme](super::phoneme). Functions are named with lowercase ARPABET
+//! symbols, so that every name is plain ASCII.
+
+use crate::builders::vowels::*;
+use crate::builders::{rhotic, SegmentBuilder};
+use crate::features::Segment;
+use crate::phoneme::Phoneme;
+use crate::phoneme::Phoneme::*;
+
+/// i, a high front tense vowel
+pub fn iy() -> Phoneme {
+    Monosegment(vowel(&[high, front, tense], 'i'))
+}
+
+/// ɪ, a near-close front lax vowel
+pub fn ih() -> Phoneme {
+    Monosegment(vowel(&[near_high, front], 'ɪ'))
+}
+
+/// ɛ, an open-mid front vowel
+pub fn eh() -> Phoneme {
+    Monosegment(vowel(&[open_mid, front], 'ɛ'))
+}
+
+/// æ, a near-open front vowel
+pub fn ae() -> Phoneme {
+    Monosegment(vowel(&[near_low, front], 'æ'))
+}
+
+/// ə, a mid central vowel (schwa)
+///
+/// Central vowels are otherwise \[+back\], which would make schwa identical
+/// to 'ʌ'. Schwa is instead left unspecified for \[back\].
+pub fn ax() -> Phoneme {
+    Monosegment(vowel(&[mid], 'ə'))
+}
+
+/// ʌ, an open-mid back unrounded vowel
+pub fn ah() -> Phoneme {
+    Monosegment(vowel(&[open_mid, back], 'ʌ'))
+}
+
+/// ɑ, a low back unrounded vowel
+pub fn aa() -> Phoneme {
+    Monosegment(vowel(&[low, back], 'ɑ'))
+}
+
+/// u, a high back tense rounded vowel
+pub fn uw() -> Phoneme {
+    Monosegment(vowel(&[high, back, rounded, tense], 'u'))
+}
+
+/// ʊ, a near-close back lax rounded vowel
+pub fn uh() -> Phoneme {
+    Monosegment(vowel(&[near_high, back, rounded], 'ʊ'))
+}
+
+/// ɔ, an open-mid back rounded vowel
+pub fn ao() -> Phoneme {
+    Monosegment(vowel(&[open_mid, back, rounded], 'ɔ'))
+}
+
+/// e͡ɪ, a diphthong
+pub fn ey() -> Phoneme {
+    Disegment(
+        vowel(&[mid, front, tense], 'e'),
+        vowel(&[near_high, front], 'ɪ'),
+    )
+}
+
+/// a͡ɪ, a diphthong
+pub fn ay() -> Phoneme {
+    Disegment(vowel(&[low, front], 'a'), vowel(&[near_high, front], 'ɪ'))
+}
+
+/// a͡ʊ, a diphthong
+pub fn aw() -> Phoneme {
+    Disegment(
+        vowel(&[low, front], 'a'),
+        vowel(&[near_high, back, rounded], 'ʊ'),
+    )
+}
+
+/// o͡ʊ, a diphthong
+pub fn ow() -> Phoneme {
+    Disegment(
+        vowel(&[mid, back, tense, rounded], 'o'),
+        vowel(&[near_high, back, rounded], 'ʊ'),
+    )
+}
+
+/// ɔ͡ɪ, a diphthong
+pub fn oy() -> Phoneme {
+    Disegment(
+        vowel(&[open_mid, back, rounded], 'ɔ'),
+        vowel(&[near_high, front], 'ɪ'),
+    )
+}
+
+/// ɜ˞, a stressed rhotic vowel
+pub fn er() -> Phoneme {
+    Monosegment(vowel(&[mid, front, tense, rhotic], 'ɝ'))
+}
+
+/// ə˞, an unstressed rhotic vowel
+pub fn axr() -> Phoneme {
+    Monosegment(vowel(&[mid, rhotic], 'ɚ'))
+}
+
+fn vowel(fs: &[fn(&mut Segment)], sym: char) -> Segment {
+    SegmentBuilder::vowel(fs, sym)
+}
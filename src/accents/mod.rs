@@ -1,3 +1,79 @@
 //! Accent definitions
 
+use crate::phoneme::Phoneme;
+use std::collections::{HashMap, HashSet};
+
 pub mod genam;
+
+/// inventory_diff compares the symbol inventories of two accents, such as the
+/// results of two accents' `symbols()` functions.
+///
+/// The result is three sets of symbols: those only in a, those only in b, and
+/// those shared by both.
+pub fn inventory_diff(
+    a: &HashSet<&str>,
+    b: &HashSet<&str>,
+) -> (HashSet<String>, HashSet<String>, HashSet<String>) {
+    (
+        a.difference(b).map(|s| s.to_string()).collect(),
+        b.difference(a).map(|s| s.to_string()).collect(),
+        a.intersection(b).map(|s| s.to_string()).collect(),
+    )
+}
+
+/// merge combines the symbol to phoneme entries of two accents (such as the
+/// results of two accents' `entries()` functions) into a single inventory.
+///
+/// When both accents have an entry for the same symbol, the entry from b is
+/// kept, so b can be used to override or extend a.
+pub fn merge(
+    a: &[(&str, Phoneme)],
+    b: &[(&str, Phoneme)],
+) -> HashMap<String, Phoneme> {
+    a.iter()
+        .chain(b)
+        .map(|(sym, p)| (sym.to_string(), *p))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builders::consonants::*;
+    use crate::builders::SegmentBuilder;
+    use std::iter::FromIterator;
+
+    fn strings(ss: &[&str]) -> HashSet<String> {
+        ss.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_inventory_diff() {
+        let genam = genam::symbols();
+        let other = HashSet::from_iter(vec!["p", "t", "k", "ɒ", "x"]);
+        let (only_genam, only_other, shared) = inventory_diff(&genam, &other);
+        assert_eq!(only_other, strings(&["ɒ", "x"]));
+        assert_eq!(shared, strings(&["p", "t", "k"]));
+        assert_eq!(only_genam.len(), genam.len() - 3);
+        assert!(only_genam.contains("ɑ"));
+    }
+
+    #[test]
+    fn test_merge() {
+        // an alveolar approximant written 'r', as in broad transcriptions
+        let broad_r = Phoneme::Monosegment(SegmentBuilder::consonant(
+            &[vd, alveolar, approximant],
+            'r',
+        ));
+        let x = Phoneme::Monosegment(SegmentBuilder::consonant(
+            &[vl, velar, fricative],
+            'x',
+        ));
+        let genam = genam::entries();
+        let merged = merge(&genam, &[("ɹ", broad_r), ("x", x)]);
+        assert_eq!(merged.len(), genam.len() + 1);
+        assert_eq!(merged["ɹ"], broad_r);
+        assert_eq!(merged["x"], x);
+        assert_eq!(Some(merged["p"]), genam::phoneme("p"));
+    }
+}
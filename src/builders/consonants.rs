@@ -2,6 +2,7 @@
 //!
 //! This module contains a collection of helpful builders for constructing consonants
 
+use super::Builder;
 use crate::features::*;
 
 /// a voiced segment
@@ -74,8 +75,9 @@ pub fn lateral(s: &mut Segment) {
 }
 
 /// a segment marked rhotic
+#[deprecated(note = "use builders::rhotic")]
 pub fn rhotic(s: &mut Segment) {
-    s.autosegmental_features.rhotic = Some(UnaryFeature::Marked);
+    super::rhotic(s)
 }
 
 /// a labially articulated segment
@@ -145,11 +147,112 @@ pub fn glottal(s: &mut Segment) {
         .get_or_insert(LaryngealFeatures::default());
 }
 
+/// a glottal stop (-continuant, -sonorant, constricted glottis) with no
+/// oral place of articulation: 'ʔ'
+pub fn glottal_stop(s: &mut Segment) {
+    glottal(s);
+    stop(s);
+    s.autosegmental_features
+        .laryngeal
+        .get_or_insert(LaryngealFeatures::default())
+        .constricted_glottis = Some(UnaryFeature::Marked)
+}
+
+/// a consonant that fills the nucleus of a syllable (+syllabic): 'n̩', 'l̩'
+pub fn syllabic(s: &mut Segment) {
+    s.root_features.syllabic = BinaryFeature::Marked;
+}
+
+/// a secondary articulation with lip rounding: 'kʷ'
+///
+/// This layers a rounded labial node onto the segment's existing place rather
+/// than replacing it, so it composes with a primary place builder like
+/// [velar].
+pub fn labialized(s: &mut Segment) {
+    s.autosegmental_features
+        .place
+        .get_or_insert(Place::default())
+        .labial
+        .get_or_insert(LabialFeature::default())
+        .round = Some(UnaryFeature::Marked)
+}
+
+/// a secondary articulation with the tongue body raised toward the palate:
+/// 'tʲ'
+///
+/// This layers a (+high, -back) dorsal node onto the segment's existing place
+/// rather than replacing it, so it composes with a primary place builder like
+/// [alveolar].
+pub fn palatalized(s: &mut Segment) {
+    let d = s
+        .autosegmental_features
+        .place
+        .get_or_insert(Place::default())
+        .dorsal
+        .get_or_insert(DorsalFeature::default());
+    d.high = Some(BinaryFeature::Marked);
+    d.back = Some(BinaryFeature::Unmarked);
+}
+
+/// a secondary articulation with the tongue body backed toward the velum:
+/// 'ɫ'
+///
+/// This layers a (+back) dorsal node onto the segment's existing place rather
+/// than replacing it, so it composes with a primary place builder like
+/// [alveolar].
+pub fn velarized(s: &mut Segment) {
+    s.autosegmental_features
+        .place
+        .get_or_insert(Place::default())
+        .dorsal
+        .get_or_insert(DorsalFeature::default())
+        .back = Some(BinaryFeature::Marked);
+}
+
+/// Every consonant builder in this module, paired with its name.
+///
+/// This allows builders to be listed and selected at runtime, such as in a
+/// segment editor.
+pub const CONSONANT_BUILDERS: &[(&str, Builder)] = &[
+    ("vd", vd),
+    ("vl", vl),
+    ("stop", stop),
+    ("nasal", nasal),
+    ("fricative", fricative),
+    ("glide", glide),
+    ("approximant", approximant),
+    ("sibilant", sibilant),
+    ("distrib", distrib),
+    ("lateral", lateral),
+    ("rhotic", super::rhotic),
+    ("bilabial", bilabial),
+    ("labiodental", labiodental),
+    ("alveolar", alveolar),
+    ("dental", dental),
+    ("postalveolar", postalveolar),
+    ("velar", velar),
+    ("palatal", palatal),
+    ("glottal", glottal),
+    ("glottal_stop", glottal_stop),
+    ("labialized", labialized),
+    ("palatalized", palatalized),
+    ("velarized", velarized),
+    ("syllabic", syllabic),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::builders::SegmentBuilder;
 
+    #[test]
+    fn test_consonant_builders() {
+        for (name, builder) in CONSONANT_BUILDERS {
+            let seg = SegmentBuilder::consonant(&[*builder], 'a');
+            assert_eq!(seg.symbol, 'a', "builder {}", name);
+        }
+    }
+
     #[test]
     fn test_vd() {
         let seg = SegmentBuilder::consonant(&[vd], 'a');
@@ -261,6 +364,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_rhotic() {
         let seg = SegmentBuilder::consonant(&[rhotic], 'a');
         assert_eq!(
@@ -361,4 +465,80 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_velarized() {
+        let seg = SegmentBuilder::consonant(&[alveolar, velarized], 'ɫ');
+        let place = seg.autosegmental_features.place.unwrap();
+        assert!(place.coronal.is_some());
+        assert_eq!(
+            place.dorsal,
+            Some(DorsalFeature {
+                high: None,
+                low: None,
+                back: Some(BinaryFeature::Marked),
+            })
+        );
+    }
+
+    #[test]
+    fn test_glottal_stop() {
+        let seg = SegmentBuilder::consonant(&[glottal_stop], 'ʔ');
+        assert_eq!(seg.root_features.sonorant, BinaryFeature::Unmarked);
+        assert_eq!(
+            seg.autosegmental_features.continuant,
+            Some(BinaryFeature::Unmarked)
+        );
+        assert_eq!(
+            seg.autosegmental_features.laryngeal,
+            Some(LaryngealFeatures {
+                voice: None,
+                spread_glottis: None,
+                constricted_glottis: Some(UnaryFeature::Marked),
+            })
+        );
+        assert_eq!(seg.autosegmental_features.place, None);
+    }
+
+    #[test]
+    fn test_syllabic() {
+        let seg =
+            SegmentBuilder::consonant(&[vd, alveolar, nasal, syllabic], 'n');
+        assert_eq!(seg.root_features.syllabic, BinaryFeature::Marked);
+        assert_eq!(seg.root_features.consonantal, BinaryFeature::Marked);
+    }
+
+    #[test]
+    fn test_labialized() {
+        let seg = SegmentBuilder::consonant(&[velar, labialized], 'k');
+        let place = seg.autosegmental_features.place.unwrap();
+        assert!(place.dorsal.is_some());
+        assert_eq!(
+            place.labial,
+            Some(LabialFeature {
+                round: Some(UnaryFeature::Marked)
+            })
+        );
+    }
+
+    #[test]
+    fn test_palatalized() {
+        let seg = SegmentBuilder::consonant(&[alveolar, palatalized], 't');
+        let place = seg.autosegmental_features.place.unwrap();
+        assert_eq!(
+            place.coronal,
+            Some(CoronalFeature {
+                anterior: Some(BinaryFeature::Marked),
+                distrib: Some(BinaryFeature::Unmarked),
+            })
+        );
+        assert_eq!(
+            place.dorsal,
+            Some(DorsalFeature {
+                high: Some(BinaryFeature::Marked),
+                low: None,
+                back: Some(BinaryFeature::Unmarked),
+            })
+        );
+    }
 }
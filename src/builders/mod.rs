@@ -6,6 +6,18 @@ pub mod words;
 
 use crate::features::*;
 
+use std::error::Error;
+use std::fmt;
+
+/// A builder function modifies some features of a segment under construction.
+pub type Builder = fn(&mut Segment);
+
+/// a segment marked rhotic, for both r-like consonants ('ɹ') and r-colored
+/// vowels ('ɚ')
+pub fn rhotic(s: &mut Segment) {
+    s.autosegmental_features.rhotic = Some(UnaryFeature::Marked);
+}
+
 /// Construct a segment using one of the methods implemented on SegmentBuilder.
 pub struct SegmentBuilder {}
 
@@ -80,6 +92,37 @@ impl SegmentBuilder {
         base
     }
 
+    /// Construct a new consonant as [`SegmentBuilder::consonant`] does,
+    /// applying only the builders whose flag is true.
+    ///
+    /// This is useful for sweeping over feature options (voiced or voiceless,
+    /// aspirated or not) without writing out a builder list for each
+    /// combination.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sound::builders::SegmentBuilder;
+    /// use sound::builders::consonants::*;
+    ///
+    /// for voiced in [true, false] {
+    ///     let sym = if voiced { 'b' } else { 'p' };
+    ///     let seg = SegmentBuilder::consonant_if(
+    ///         &[(voiced, vd), (!voiced, vl), (true, bilabial), (true, stop)],
+    ///         sym,
+    ///     );
+    ///     assert_eq!(seg.symbol, sym);
+    /// }
+    /// ```
+    pub fn consonant_if(builders: &[(bool, Builder)], sym: char) -> Segment {
+        let chosen: Vec<Builder> = builders
+            .iter()
+            .filter(|(flag, _)| *flag)
+            .map(|(_, f)| *f)
+            .collect();
+        SegmentBuilder::consonant(&chosen, sym)
+    }
+
     /// Construct a new vowel (-consonantal, +sonorant, +syllabic) from a collection
     /// of builder functions.
     ///
@@ -107,6 +150,150 @@ impl SegmentBuilder {
 
         base
     }
+
+    /// Construct a new consonant as [`SegmentBuilder::consonant`] does, but
+    /// check that the result is coherent.
+    ///
+    /// Because builders are applied blindly in order, a later builder can
+    /// silently overwrite a feature set by an earlier one (as with `stop` and
+    /// `fricative`, which disagree about \[continuant\]). consonant_checked
+    /// fails with a [BuildError] when two builders set the same root, manner,
+    /// or voice feature to different values, or when the finished segment
+    /// combines features that cannot co-occur:
+    /// - a +syllabic segment must be +sonorant
+    /// - a nasal segment must be +sonorant
+    ///
+    /// A +consonantal segment may be +syllabic, so that syllabic consonants
+    /// ('n̩', 'l̩') can be built with the
+    /// [syllabic](consonants::syllabic) builder. Such a segment must still be
+    /// +sonorant, so a syllabic obstruent is rejected.
+    ///
+    /// Place features and \[strident\] are not checked for conflicts, because
+    /// place builders are meant to be layered in order to refine one another
+    /// (`alveolar` followed by `distrib`, for example).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sound::builders::SegmentBuilder;
+    /// use sound::builders::consonants::*;
+    ///
+    /// assert!(SegmentBuilder::consonant_checked(&[vl, bilabial, stop], 'p').is_ok());
+    /// assert!(SegmentBuilder::consonant_checked(&[vl, bilabial, stop, fricative], 'p').is_err());
+    /// ```
+    pub fn consonant_checked(
+        builders: &[Builder],
+        sym: char,
+    ) -> Result<Segment, BuildError> {
+        check_builders(builders)?;
+        let seg = SegmentBuilder::consonant(builders, sym);
+        check_coherence(seg)?;
+        Ok(seg)
+    }
+}
+
+/// An error created when a segment is built from builders that contradict one
+/// another, or that produce a segment with incoherent features.
+#[derive(Debug)]
+pub struct BuildError {
+    msg: String,
+}
+
+impl BuildError {
+    fn new(msg: &str) -> BuildError {
+        BuildError {
+            msg: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl Error for BuildError {}
+
+// Find two builders that set the same feature to different values. Each
+// builder is applied on its own, so that the features it writes can be
+// compared with the features written by every other builder.
+fn check_builders(builders: &[Builder]) -> Result<(), BuildError> {
+    let writes: Vec<([Option<BinaryFeature>; 3], Segment)> =
+        builders.iter().map(|f| written_features(*f)).collect();
+
+    for (i, (root_a, seg_a)) in writes.iter().enumerate() {
+        for (j, (root_b, seg_b)) in writes.iter().enumerate().skip(i + 1) {
+            let root_conflict = ROOT_FEATURE_NAMES
+                .iter()
+                .zip(root_a.iter().zip(root_b))
+                .find(|(_, (a, b))| a.is_some() && b.is_some() && a != b)
+                .map(|(name, _)| *name);
+            let feature = match root_conflict {
+                Some(name) => name,
+                None => match seg_a.unify(seg_b) {
+                    Ok(_) => continue,
+                    Err(e) => e.feature(),
+                },
+            };
+            return Err(BuildError::new(&format!(
+                "ConflictingBuilders: builders {} and {} set [{}] differently",
+                i, j, feature
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+const ROOT_FEATURE_NAMES: [&str; 3] = ["consonantal", "sonorant", "syllabic"];
+
+// The features written by a single builder. Root features are always
+// specified, so a root feature counts as written when the builder gives it the
+// same value from both an all-Unmarked and an all-Marked base. The segment
+// returned carries the builder's autosegmental features on a fixed root, less
+// the features that builders refine by layering (place and strident).
+fn written_features(
+    f: fn(&mut Segment),
+) -> ([Option<BinaryFeature>; 3], Segment) {
+    let mut unmarked = mk_base(' ');
+    let mut marked = mk_base(' ');
+    marked.root_features = RootFeatures {
+        consonantal: BinaryFeature::Marked,
+        sonorant: BinaryFeature::Marked,
+        syllabic: BinaryFeature::Marked,
+    };
+    f(&mut unmarked);
+    f(&mut marked);
+
+    let written =
+        |a: BinaryFeature, b: BinaryFeature| Some(a).filter(|_| a == b);
+    let (u, m) = (unmarked.root_features, marked.root_features);
+    let root = [
+        written(u.consonantal, m.consonantal),
+        written(u.sonorant, m.sonorant),
+        written(u.syllabic, m.syllabic),
+    ];
+
+    unmarked.root_features = mk_base(' ').root_features;
+    unmarked.autosegmental_features.place = None;
+    unmarked.autosegmental_features.strident = None;
+    (root, unmarked)
+}
+
+fn check_coherence(seg: Segment) -> Result<(), BuildError> {
+    let sonorant = seg.root_features.sonorant == BinaryFeature::Marked;
+    if seg.root_features.syllabic == BinaryFeature::Marked && !sonorant {
+        return Err(BuildError::new(
+            "IncoherentFeatures: a +syllabic segment must be +sonorant",
+        ));
+    }
+    if seg.autosegmental_features.nasal.is_some() && !sonorant {
+        return Err(BuildError::new(
+            "IncoherentFeatures: a nasal segment must be +sonorant",
+        ));
+    }
+    Ok(())
 }
 
 fn mk_base(sym: char) -> Segment {
@@ -135,6 +322,31 @@ mod tests {
         assert_eq!(seg.symbol, 'y');
     }
 
+    #[test]
+    // Only flagged builders are applied
+    fn test_consonant_if() {
+        use crate::builders::consonants::*;
+
+        let built = |voiced: bool| {
+            SegmentBuilder::consonant_if(
+                &[(voiced, vd), (!voiced, vl), (true, bilabial), (true, stop)],
+                'p',
+            )
+        };
+        assert_eq!(
+            built(true),
+            SegmentBuilder::consonant(&[vd, bilabial, stop], 'p')
+        );
+        assert_eq!(
+            built(false),
+            SegmentBuilder::consonant(&[vl, bilabial, stop], 'p')
+        );
+        assert_eq!(
+            SegmentBuilder::consonant_if(&[(false, vd)], 'p'),
+            SegmentBuilder::consonant(&[], 'p')
+        );
+    }
+
     #[test]
     // Segment is by default unmarked and empty
     fn test_segment() {
@@ -212,4 +424,65 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_consonant_checked() {
+        use consonants::*;
+        assert_eq!(
+            SegmentBuilder::consonant_checked(&[vd, alveolar, nasal], 'n')
+                .unwrap(),
+            SegmentBuilder::consonant(&[vd, alveolar, nasal], 'n')
+        );
+        assert!(SegmentBuilder::consonant_checked(
+            &[vd, alveolar, nasal, syllabic],
+            'n'
+        )
+        .is_ok());
+        // layered place builders are not conflicts
+        assert!(SegmentBuilder::consonant_checked(
+            &[vd, alveolar, lateral, distrib, approximant],
+            'l'
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_consonant_checked_conflicting_builders() {
+        use consonants::*;
+        let err =
+            SegmentBuilder::consonant_checked(&[vl, stop, fricative], 't')
+                .unwrap_err();
+        assert!(err.to_string().contains("continuant"));
+        let err = SegmentBuilder::consonant_checked(&[vl, vd, stop], 't')
+            .unwrap_err();
+        assert!(err.to_string().contains("voice"));
+        let err =
+            SegmentBuilder::consonant_checked(&[stop, nasal], 'n').unwrap_err();
+        assert!(err.to_string().contains("sonorant"));
+    }
+
+    #[test]
+    fn test_consonant_checked_incoherent() {
+        use consonants::*;
+        let err = SegmentBuilder::consonant_checked(
+            &[vl, alveolar, stop, syllabic],
+            't',
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("IncoherentFeatures"));
+        let err = SegmentBuilder::consonant_checked(
+            &[vl, fricative, vowels::nasalized],
+            's',
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("IncoherentFeatures"));
+    }
+
+    #[test]
+    fn test_rhotic() {
+        let c = SegmentBuilder::consonant(&[rhotic], 'ɹ');
+        let v = SegmentBuilder::vowel(&[rhotic], 'ɚ');
+        assert_eq!(c.autosegmental_features.rhotic, Some(UnaryFeature::Marked));
+        assert_eq!(v.autosegmental_features.rhotic, Some(UnaryFeature::Marked));
+    }
 }
@@ -2,6 +2,7 @@
 //!
 //! This module contains a collection of helpful builders for constructing vowels
 
+use super::Builder;
 use crate::features::*;
 
 /// tongue is behind neutral position (forward-back)
@@ -86,6 +87,57 @@ pub fn low(s: &mut Segment) {
     d.high = Some(BinaryFeature::Unmarked);
 }
 
+/// tongue body is high, but lax (-ATR): near-close vowels like 'ɪ', 'ʊ'
+///
+/// Vowel height is encoded with only \[+/-high\], \[+/-low\], and
+/// \[+/-ATR\], so the seven heights of the IPA chart are told apart by
+/// tongue root advancement where they can be:
+///
+/// | height      | builders       | high | low | ATR  | examples |
+/// |-------------|----------------|------|-----|------|----------|
+/// | close       | high, tense    | +    | -   | +    | i, u     |
+/// | near-close  | near_high      | +    | -   | -    | ɪ, ʊ     |
+/// | close-mid   | mid, tense     | -    | -   | +    | e, o     |
+/// | mid         | mid            | -    | -   | -    | e̞, ə     |
+/// | open-mid    | open_mid       | -    | -   | -    | ɛ, ɔ     |
+/// | near-open   | near_low       | -    | +   | -    | æ        |
+/// | open        | low            | -    | +   | none | a, ɑ     |
+///
+/// Close-mid "e" and open-mid "ɛ" therefore differ only in \[ATR\]. True-mid
+/// vowels have the same height features as open-mid vowels, and can only be
+/// told apart from them by other features (GenAm "ə" has no backness). The
+/// near_high, open_mid, and near_low builders set \[-ATR\] themselves, so
+/// they give lax vowels regardless of whether [tense] was applied before
+/// them.
+pub fn near_high(s: &mut Segment) {
+    high(s);
+    lax(s);
+}
+
+/// tongue body is neither high nor low, and lax (-ATR): open-mid vowels like
+/// 'ɛ', 'ɔ'. See [near_high] for how vowel heights are encoded.
+pub fn open_mid(s: &mut Segment) {
+    mid(s);
+    lax(s);
+}
+
+/// tongue body is low, and lax (-ATR): near-open vowels like 'æ'. Fully open
+/// vowels built with [low] have no \[ATR\] specification. See [near_high]
+/// for how vowel heights are encoded.
+pub fn near_low(s: &mut Segment) {
+    low(s);
+    lax(s);
+}
+
+fn lax(s: &mut Segment) {
+    s.autosegmental_features
+        .place
+        .get_or_insert(Place::default())
+        .pharyngeal
+        .get_or_insert(PharyngealFeature::default())
+        .advanced_tongue_root = Some(BinaryFeature::Unmarked)
+}
+
 /// rounding or pursing of the lips
 pub fn rounded(s: &mut Segment) {
     s.autosegmental_features
@@ -121,15 +173,53 @@ pub fn tense(s: &mut Segment) {
 }
 
 /// r-colored sounds produced by a raised or curled tongue tip
+#[deprecated(note = "use builders::rhotic")]
 pub fn rhotic(s: &mut Segment) {
-    s.autosegmental_features.rhotic = Some(UnaryFeature::Marked)
+    super::rhotic(s)
 }
 
+/// air passes through the nasal tract during the vowel: 'ɑ̃', 'ɛ̃'
+///
+/// Unlike the consonant [nasal](super::consonants::nasal) builder, this only
+/// marks the nasal feature and leaves continuant and sonorant untouched.
+pub fn nasalized(s: &mut Segment) {
+    s.autosegmental_features.nasal = Some(UnaryFeature::Marked)
+}
+
+/// Every vowel builder in this module, paired with its name.
+///
+/// This allows builders to be listed and selected at runtime, such as in a
+/// segment editor.
+pub const VOWEL_BUILDERS: &[(&str, Builder)] = &[
+    ("back", back),
+    ("front", front),
+    ("central", central),
+    ("high", high),
+    ("mid", mid),
+    ("low", low),
+    ("near_high", near_high),
+    ("open_mid", open_mid),
+    ("near_low", near_low),
+    ("rounded", rounded),
+    ("unrounded", unrounded),
+    ("tense", tense),
+    ("rhotic", super::rhotic),
+    ("nasalized", nasalized),
+];
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::builders::SegmentBuilder;
 
+    #[test]
+    fn test_vowel_builders() {
+        for (name, builder) in VOWEL_BUILDERS {
+            let seg = SegmentBuilder::vowel(&[*builder], 'a');
+            assert_eq!(seg.symbol, 'a', "builder {}", name);
+        }
+    }
+
     #[test]
     fn test_front() {
         let seg = SegmentBuilder::vowel(&[front], 'a');
@@ -196,6 +286,35 @@ mod tests {
         assert_eq!(d.high, Some(BinaryFeature::Unmarked));
     }
 
+    #[test]
+    fn test_height_levels() {
+        let dorsal_atr = |builders: &[fn(&mut Segment)]| {
+            let place = SegmentBuilder::vowel(builders, 'a')
+                .autosegmental_features
+                .place
+                .unwrap();
+            let d = place.dorsal.unwrap();
+            (
+                d.high,
+                d.low,
+                place.pharyngeal.and_then(|p| p.advanced_tongue_root),
+            )
+        };
+        let (m, u) =
+            (Some(BinaryFeature::Marked), Some(BinaryFeature::Unmarked));
+        assert_eq!(dorsal_atr(&[high, tense]), (m, u, m));
+        assert_eq!(dorsal_atr(&[near_high]), (m, u, u));
+        assert_eq!(dorsal_atr(&[tense, near_high]), (m, u, u));
+        assert_eq!(dorsal_atr(&[mid, tense]), (u, u, m));
+        assert_eq!(dorsal_atr(&[open_mid]), (u, u, u));
+        assert_eq!(dorsal_atr(&[near_low]), (u, m, u));
+        assert_eq!(dorsal_atr(&[low]), (u, m, None));
+
+        let close_mid = SegmentBuilder::vowel(&[mid, front, tense], 'e');
+        let open_mid = SegmentBuilder::vowel(&[open_mid, front], 'ɛ');
+        assert!(!close_mid.feature_eq(&open_mid));
+    }
+
     #[test]
     fn test_rounded() {
         let seg = SegmentBuilder::vowel(&[rounded], 'a');
@@ -239,6 +358,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_rhotic() {
         let seg = SegmentBuilder::vowel(&[rhotic], 'a');
         assert_eq!(
@@ -246,4 +366,15 @@ mod tests {
             Some(UnaryFeature::Marked)
         );
     }
+
+    #[test]
+    fn test_nasalized() {
+        let seg = SegmentBuilder::vowel(&[nasalized], 'a');
+        assert_eq!(
+            seg.autosegmental_features.nasal,
+            Some(UnaryFeature::Marked)
+        );
+        assert_eq!(seg.root_features.sonorant, BinaryFeature::Marked);
+        assert_eq!(seg.autosegmental_features.continuant, None);
+    }
 }
@@ -2,14 +2,18 @@
 //!
 //! Builders::Words provides a constructor for words. It parses a word description into a structured word (syllables and phonemes), using a given accent's phoneme lookup function.
 
+use crate::accents::genam;
 use crate::feature_classes;
 use crate::phoneme::Phoneme;
 use crate::stress::Stress;
 use crate::syllable::Syllable;
+use crate::tone::Tone;
 use crate::word::Word;
 
 use std::error::Error;
 use std::fmt;
+use std::iter::{Enumerate, Peekable};
+use std::str::Chars;
 
 /// from_accent constructs a word from a word description using an accent's
 /// phoneme function.
@@ -22,10 +26,22 @@ use std::fmt;
 /// as it is given in a dictionary. Stress information may be given with IPA stress marks (ˈˌ.) or
 /// numbers (1, 2, 3, 4) to mark stress levels and separate syllables. The absence of a stress mark
 /// from the first syllable of a word is interpretted as unstressed (. or 3). Because there is no
-/// IPA symbol for reduced stress, it must be marked using a number (4).
+/// IPA symbol for reduced stress, it must be marked using a number (4). A syllable break (.)
+/// directly followed by a stress mark or number only separates syllables, so "ˈti.4tə" gives the
+/// second syllable reduced stress.
 ///
-/// For syllabization to work, from_accent() expects exactly one -consonantal phoneme (a vowel) in
-/// each syllable.
+/// Multi-character symbols are joined with the tie bar U+0361 (t͡ʃ, a͡ɪ), or the lower tie bar
+/// U+035C (t͜ʃ). The tie bar may also be written before both symbols (͡tʃ). Two adjacent symbols
+/// written without a tie bar (tʃ) are also read as a single symbol when the accent recognizes the
+/// pair as a phoneme.
+///
+/// For syllabization to work, from_accent() expects exactly one +syllabic phoneme (a vowel or a
+/// syllabic consonant like n̩) in each syllable.
+///
+/// Tone may be given for a syllable with Chao tone letters (ma˧˥) or a combining tone diacritic
+/// following the vowel (ma\u{0301}). Precomposed letters with tone marks (á) are not decomposed.
+/// See [Tone::from_symbols] for the recognized marks. Syllables without tone marks have None as
+/// their tone.
 ///
 /// Because lexical stress is only useful as a comparison between syllables of the same word,
 /// single syllable words should have None as their stress information.
@@ -33,15 +49,161 @@ pub fn from_accent(
     accent: fn(&str) -> Option<Phoneme>,
     word_desc: &str,
 ) -> Result<Word, WordConstructorError> {
-    let syls_as_symbols = split_word_desc(word_desc)?;
+    from_accent_with(accent, word_desc, ParseOptions::default())
+}
+
+/// ParseOptions adjusts how [from_accent_with] reads a word description.
+///
+/// The default options parse exactly as [from_accent] does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Combine two adjacent vowels within a syllable into a single diphthong
+    /// (disegment) nucleus, rather than rejecting the syllable.
+    ///
+    /// This allows diphthongs written as two vowel symbols without a tie bar
+    /// (ai), but it makes the description ambiguous: a sequence of two vowels
+    /// in hiatus (as in "na.ive") must then be separated by an explicit
+    /// syllable break, or it is read as one diphthong. Only two monosegment
+    /// vowels are merged; a third vowel, or a vowel following a disegment,
+    /// is still an error.
+    pub merge_adjacent_vowels: bool,
+
+    /// The stress given to a first syllable with no stress mark. Later
+    /// syllables always begin with a mark, so they are unaffected.
+    pub default_stress: Stress,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions {
+            merge_adjacent_vowels: false,
+            default_stress: Stress::Unstressed,
+        }
+    }
+}
+
+/// from_accent_with constructs a word from a word description as
+/// [from_accent] does, with the given [ParseOptions].
+pub fn from_accent_with(
+    accent: fn(&str) -> Option<Phoneme>,
+    word_desc: &str,
+    opts: ParseOptions,
+) -> Result<Word, WordConstructorError> {
+    build_syllables(accent, word_desc, opts, None).map(Word::from)
+}
+
+/// from_accent_with_default constructs a word from a word description as
+/// [from_accent] does, except that a first syllable with no stress mark is
+/// given the default stress rather than Unstressed.
+///
+/// A syllable break (.) marks the syllable after it as Unstressed, so a
+/// description that starts with "." has an explicitly Unstressed first
+/// syllable, and the default is not used.
+///
+/// from_accent_with_default(genam::phoneme, "hɛ.lo͡ʊ", Stress::Stressed)
+/// -> Ok(word for "ˈhɛ.lo͡ʊ")
+pub fn from_accent_with_default(
+    accent: fn(&str) -> Option<Phoneme>,
+    word_desc: &str,
+    default: Stress,
+) -> Result<Word, WordConstructorError> {
+    let opts = ParseOptions {
+        default_stress: default,
+        ..ParseOptions::default()
+    };
+    from_accent_with(accent, word_desc, opts)
+}
+
+/// from_accent_batch constructs words from a list of (headword, word description) entries, as
+/// from a pronouncing dictionary, using an accent's phoneme function.
+///
+/// Each entry is parsed with [from_accent]. Words that parse are returned with their headwords
+/// in the first list, and entries that fail are returned with their headwords and errors in the
+/// second list. Both lists keep the order of the entries.
+pub fn from_accent_batch(
+    accent: fn(&str) -> Option<Phoneme>,
+    entries: &[(&str, &str)],
+) -> BatchResult {
+    let mut words = Vec::new();
+    let mut errors = Vec::new();
+    for (headword, word_desc) in entries {
+        match from_accent(accent, word_desc) {
+            Ok(word) => words.push((headword.to_string(), word)),
+            Err(e) => errors.push((headword.to_string(), e)),
+        }
+    }
+    (words, errors)
+}
+
+/// The result of [from_accent_batch]: parsed words and failed entries, each paired with its
+/// headword.
+pub type BatchResult =
+    (Vec<(String, Word)>, Vec<(String, WordConstructorError)>);
+
+/// from_accent_lenient constructs a best-effort word from a word description, collecting problems
+/// instead of failing on the first one.
+///
+/// Symbols that the accent does not recognize are skipped and returned as
+/// [ParseProblem::UnknownSymbol]. Tone marks that do not spell a recognized tone are skipped
+/// ([ParseProblem::UnknownTone]), leaving their syllable without a tone. If skipping symbols
+/// leaves a syllable without a nucleus, that syllable is dropped ([ParseProblem::DroppedSyllable]).
+/// Errors that cannot be skipped (an empty description, two nuclei in one syllable, ...) produce
+/// no word and are returned as [ParseProblem::Fatal], after any problems found before them.
+pub fn from_accent_lenient(
+    accent: fn(&str) -> Option<Phoneme>,
+    word_desc: &str,
+) -> (Option<Word>, Vec<ParseProblem>) {
+    let mut problems = Vec::new();
+    match build_syllables(
+        accent,
+        word_desc,
+        ParseOptions::default(),
+        Some(&mut problems),
+    ) {
+        Ok(syls) if !syls.is_empty() => (Some(Word::from(syls)), problems),
+        Ok(_) => (None, problems),
+        Err(e) => {
+            problems.push(ParseProblem::Fatal(e));
+            (None, problems)
+        }
+    }
+}
+
+/// A problem found by [from_accent_lenient].
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseProblem {
+    /// A symbol that the accent does not recognize, which was skipped
+    UnknownSymbol(String),
+    /// Tone marks that do not spell a recognized tone, which were skipped
+    UnknownTone(String),
+    /// A syllable (counting from 1) left without a nucleus, which was dropped
+    DroppedSyllable(usize),
+    /// An error that could not be skipped, so that no word was constructed
+    Fatal(WordConstructorError),
+}
 
-    let multiple_syllables_flag = syls_as_symbols.len() > 1;
+// Construct the syllables of a word from a word description. When problems is
+// given, unknown symbols, unknown tones, and syllables left without a nucleus
+// are recorded there and skipped instead of causing an error.
+fn build_syllables(
+    accent: fn(&str) -> Option<Phoneme>,
+    word_desc: &str,
+    opts: ParseOptions,
+    mut problems: Option<&mut Vec<ParseProblem>>,
+) -> Result<Vec<Syllable>, WordConstructorError> {
+    let default_stress = match opts.default_stress {
+        Stress::Stressed => '1',
+        Stress::SecondaryStress => '2',
+        Stress::Unstressed => '3',
+        Stress::ReducedStress => '4',
+    };
+    let syls_as_symbols = split_word_desc(accent, word_desc, default_stress)?;
 
     // Construct each syllable and push to syls
     let mut syls = Vec::new();
     for mut syl_as_symbols in syls_as_symbols {
         // first symbol should be stress information
-        let stress = match syl_as_symbols.remove(0).as_str() {
+        let stress = match syl_as_symbols.remove(0).0.as_str() {
             "1" => Stress::Stressed,
             "2" => Stress::SecondaryStress,
             "3" => Stress::Unstressed,
@@ -57,25 +219,52 @@ pub fn from_accent(
         let mut onset = vec![];
         let mut nucleus_maybe: Option<Phoneme> = None;
         let mut coda = vec![];
-        for symbol in syl_as_symbols {
+        let mut tone_symbols = String::new();
+        for (symbol, position) in syl_as_symbols {
+            // tone marks are collected and read once the syllable is complete
+            if symbol.chars().all(Tone::is_tone_symbol) {
+                tone_symbols.push_str(&symbol);
+                continue;
+            }
+
             // lookup phoneme for symbol or fail
-            let phoneme = accent(&symbol).ok_or_else(|| {
-                WordConstructorError::new(&format!(
-                    "UnknownSymbol: {} not recognized in accent",
-                    symbol
-                ))
-            })?;
-
-            // vowels: only 1 vowel is permitted in a syllable
-            if feature_classes::is_vowel(phoneme) {
-                nucleus_maybe = match nucleus_maybe {
-                    None => Ok(Some(phoneme)),
-                    Some(existing_phoneme) => {
-                        Err(WordConstructorError::new(&format!(
-                            "BadSylStructure: two phonemes in syl: {}/{}",
-                            existing_phoneme.symbol(),
-                            symbol,
-                        )))
+            let phoneme = match (accent(&symbol), problems.as_mut()) {
+                (Some(phoneme), _) => phoneme,
+                (None, Some(problems)) => {
+                    problems.push(ParseProblem::UnknownSymbol(symbol));
+                    continue;
+                }
+                (None, None) => {
+                    return Err(WordConstructorError::at(
+                        &format!(
+                            "UnknownSymbol: {} not recognized in accent",
+                            symbol
+                        ),
+                        position,
+                    ))
+                }
+            };
+
+            // vowels: only 1 vowel (or syllabic consonant) is permitted in a
+            // syllable
+            if feature_classes::is_syllabic(phoneme) {
+                nucleus_maybe = match (nucleus_maybe, phoneme) {
+                    (None, _) => Ok(Some(phoneme)),
+                    (
+                        Some(Phoneme::Monosegment(first)),
+                        Phoneme::Monosegment(second),
+                    ) if opts.merge_adjacent_vowels && coda.is_empty() => {
+                        Ok(Some(Phoneme::Disegment(first, second)))
+                    }
+                    (Some(existing_phoneme), _) => {
+                        Err(WordConstructorError::at(
+                            &format!(
+                                "BadSylStructure: two phonemes in syl: {}/{}",
+                                existing_phoneme.symbol(),
+                                symbol,
+                            ),
+                            position,
+                        ))
                     }
                 }?;
             //consonants: simply dependent on the vowel
@@ -86,39 +275,320 @@ pub fn from_accent(
             }
         } // for: end symbol iteration in syllable
 
+        let tone = match (Tone::from_symbols(&tone_symbols), problems.as_mut())
+        {
+            _ if tone_symbols.is_empty() => None,
+            (Some(tone), _) => Some(tone),
+            (None, Some(problems)) => {
+                problems.push(ParseProblem::UnknownTone(tone_symbols));
+                None
+            }
+            (None, None) => {
+                return Err(WordConstructorError::new(&format!(
+                    "BadTone: {} is not a recognized tone",
+                    tone_symbols
+                )))
+            }
+        };
+
         // ensure there was a vowel in the syllable
-        let nucleus = nucleus_maybe.ok_or_else(|| {
-            WordConstructorError::new("BadSylStructure: no nucleus in syllable")
-        })?;
+        let nucleus = match (nucleus_maybe, problems.as_mut()) {
+            (Some(nucleus), _) => nucleus,
+            (None, Some(problems)) => {
+                problems.push(ParseProblem::DroppedSyllable(syls.len() + 1));
+                continue;
+            }
+            (None, None) => {
+                return Err(WordConstructorError::new(
+                    "BadSylStructure: no nucleus in syllable",
+                ))
+            }
+        };
 
         syls.push(Syllable {
             onset,
             nucleus,
             coda,
-            stress: if multiple_syllables_flag {
-                Some(stress)
-            } else {
-                None
-            },
+            stress: Some(stress),
+            tone,
         });
     } // for: end syllable iteration
 
-    Ok(syls.into())
+    // stress is only meaningful between the syllables of a word
+    if syls.len() == 1 {
+        syls[0].stress = None;
+    }
+
+    Ok(syls)
+}
+
+/// from_ipa constructs a word from an IPA transcription as it might appear in
+/// a dictionary, using an accent's phoneme function.
+///
+/// from_ipa(genam::phoneme, "/ˈkæt/")
+/// from_ipa(genam::phoneme, "\[ˈkæt\]")
+///
+/// Surrounding slashes (/.../) or brackets (\[...\]) are removed, length marks (ː) are ignored,
+/// and the remaining description is passed to [from_accent]. Any error positions refer to the
+/// cleaned description rather than the original string.
+pub fn from_ipa(
+    accent: fn(&str) -> Option<Phoneme>,
+    s: &str,
+) -> Result<Word, WordConstructorError> {
+    let trimmed = s.trim();
+    let unwrapped = trimmed
+        .strip_prefix('/')
+        .and_then(|t| t.strip_suffix('/'))
+        .or_else(|| trimmed.strip_prefix('[').and_then(|t| t.strip_suffix(']')))
+        .unwrap_or(trimmed);
+    let word_desc: String = unwrapped.chars().filter(|&c| c != 'ː').collect();
+    from_accent(accent, &word_desc)
+}
+
+/// from_arpabet constructs a GenAm word from an ARPAbet transcription, as
+/// given in the CMU Pronouncing Dictionary.
+///
+/// from_arpabet("K AE1 T") -> Ok(word for cat)
+/// from_arpabet("AH0 L AW1")
+///
+/// Phones are separated by whitespace, and each vowel carries a trailing CMU
+/// stress digit (0, 1, 2), read with [Stress::from_cmu]. ARPAbet has no
+/// separate reduced vowels, so unstressed "AH0" and "ER0" are read as "ə" and
+/// "ə˞". See [genam::from_arpabet] for the phones that are recognized.
+///
+/// ARPAbet does not mark syllable boundaries, so they are placed here with a
+/// simple rule: of the consonants between two vowels, the last begins the
+/// following syllable and any others close the preceding one ("AE1 K R AH0"
+/// -> "ˈæk.ɹə"). "ŋ" never begins a syllable. As with [from_accent], single
+/// syllable words have None as their stress information.
+pub fn from_arpabet(arpa: &str) -> Result<Word, WordConstructorError> {
+    let mut phones = Vec::new();
+    let mut position = 0;
+    for token in arpa.split(char::is_whitespace) {
+        if !token.is_empty() {
+            phones.push(arpabet_phone(token, position)?);
+        }
+        position += token.chars().count() + 1;
+    }
+
+    let nuclei: Vec<usize> = phones
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, stress))| stress.is_some())
+        .map(|(i, _)| i)
+        .collect();
+    if nuclei.is_empty() {
+        return Err(WordConstructorError::new(
+            "BadSylStructure: no nucleus in syllable",
+        ));
+    }
+
+    // each syllable begins at the last consonant before its nucleus, unless
+    // that consonant is ŋ or the first syllable (which takes every consonant)
+    let mut starts = vec![0];
+    for (prev, &next) in nuclei.iter().zip(nuclei.iter().skip(1)) {
+        let onset_taken = next - prev > 1 && phones[next - 1].0.symbol() != "ŋ";
+        starts.push(if onset_taken { next - 1 } else { next });
+    }
+    starts.push(phones.len());
+
+    let mut syls: Vec<Syllable> = nuclei
+        .iter()
+        .zip(starts.windows(2))
+        .map(|(&nucleus, bounds)| {
+            let only_phonemes =
+                |range: &[(Phoneme, Option<Stress>)]| -> Vec<Phoneme> {
+                    range.iter().map(|(p, _)| *p).collect()
+                };
+            Syllable {
+                onset: only_phonemes(&phones[bounds[0]..nucleus]),
+                nucleus: phones[nucleus].0,
+                coda: only_phonemes(&phones[nucleus + 1..bounds[1]]),
+                stress: phones[nucleus].1,
+                tone: None,
+            }
+        })
+        .collect();
+
+    // stress is only meaningful between the syllables of a word
+    if syls.len() == 1 {
+        syls[0].stress = None;
+    }
+
+    Ok(Word::from(syls))
+}
+
+// Read one ARPAbet phone with its optional stress digit. Vowels must have a
+// stress digit and consonants must not.
+fn arpabet_phone(
+    token: &str,
+    position: usize,
+) -> Result<(Phoneme, Option<Stress>), WordConstructorError> {
+    let (code, digit) = match token.char_indices().last() {
+        Some((i, c)) if c.is_ascii_digit() => {
+            (&token[..i], c.to_digit(10).map(|d| d as u8))
+        }
+        _ => (token, None),
+    };
+    let stress = match digit {
+        Some(digit) => Some(Stress::from_cmu(digit).ok_or_else(|| {
+            WordConstructorError::at(
+                &format!("BadStress: {} is not a CMU stress digit", digit),
+                position,
+            )
+        })?),
+        None => None,
+    };
+    let phoneme = match (code.to_ascii_uppercase().as_str(), stress) {
+        ("AH", Some(Stress::Unstressed)) => genam::phoneme("ə"),
+        ("ER", Some(Stress::Unstressed)) => genam::phoneme("ə˞"),
+        _ => genam::from_arpabet(code),
+    }
+    .ok_or_else(|| {
+        WordConstructorError::at(
+            &format!("UnknownSymbol: {} not recognized in ARPAbet", code),
+            position,
+        )
+    })?;
+
+    match (feature_classes::is_syllabic(phoneme), stress) {
+        (true, None) => Err(WordConstructorError::at(
+            &format!("BadSylStructure: no stress digit on vowel {}", token),
+            position,
+        )),
+        (false, Some(_)) => Err(WordConstructorError::at(
+            &format!("BadSylStructure: stress digit on consonant {}", token),
+            position,
+        )),
+        _ => Ok((phoneme, stress)),
+    }
+}
+
+/// roundtrip_ok tests whether a word description survives being parsed,
+/// rendered back with [Word::symbols], and parsed again, giving the same word
+/// both times. A description that does not parse is not ok.
+///
+/// This is meant as a testing aid for accents and for the rendering of words.
+pub fn roundtrip_ok(
+    accent: fn(&str) -> Option<Phoneme>,
+    word_desc: &str,
+) -> bool {
+    match from_accent(accent, word_desc) {
+        Ok(word) => from_accent(accent, &word.clone().symbols())
+            .is_ok_and(|w| w == word),
+        Err(_) => false,
+    }
+}
+
+/// tokenize_ipa splits a bare IPA string (without stress marks or syllable
+/// breaks) into phoneme-sized symbols, without reference to an accent.
+///
+/// tokenize_ipa("t͡ʃə˞tʃ") -> \["t͡ʃ", "ə˞", "t", "ʃ"\]
+///
+/// A tie bar (U+0361 or U+035C) joins the symbols on either side of it, and
+/// the rhotic hook (˞) and syllabic marks (U+0329, U+030D) are kept with the
+/// symbol they follow. Tie bars are given as U+0361 in the symbols returned.
+/// Every other character is its own symbol. Because no accent is consulted,
+/// two symbols written without a tie bar (tʃ) are not joined, as they are in
+/// [from_accent]. A tie bar with no symbol after it is dropped.
+pub fn tokenize_ipa(s: &str) -> Vec<String> {
+    let mut chars = s.chars().enumerate().peekable();
+    let mut tokens = Vec::new();
+    while let Some((position, c)) = chars.next() {
+        match read_symbol((position, c), &mut chars) {
+            Ok(token) => tokens.push(token),
+            Err(_) if !TIE_BARS.contains(&c) => tokens.push(String::from(c)),
+            Err(_) => {}
+        }
+    }
+    tokens
+}
+
+// Marks that are written after a symbol and belong to it: the rhotic hook
+// (ə˞) and the syllabic marks below and above (n̩, n̍)
+const MODIFIERS: &[char] = &['\u{02DE}', '\u{0329}', '\u{030D}'];
+
+// The tie bar above (U+0361) and below (U+035C), either of which joins two
+// symbols into one (t͡ʃ, t͜ʃ)
+const TIE_BARS: &[char] = &['\u{0361}', '\u{035C}'];
+
+// Read one symbol starting at first, taking from chars any marks that belong
+// to it: modifiers, and a tie bar with the symbol that it joins. The tie bar
+// may come before or after the modifiers of the first symbol
+// ("n\u{0329}\u{0361}t" or "n\u{0361}\u{0329}t"), or before both symbols
+// ("\u{0361}tʃ"), and is always given as U+0361 in the symbol read.
+// A tie bar with nothing after it gives Err with the tie bar's position.
+fn read_symbol(
+    first: (usize, char),
+    chars: &mut Peekable<Enumerate<Chars<'_>>>,
+) -> Result<String, usize> {
+    let (position, c) = first;
+    let mut symbol = String::new();
+    let mut joined = false;
+    if TIE_BARS.contains(&c) {
+        let (_, base) = chars.next().ok_or(position)?;
+        let (_, connected) = chars.next().ok_or(position)?;
+        symbol.push(base);
+        symbol.push('\u{0361}');
+        symbol.push(connected);
+        joined = true;
+    } else {
+        symbol.push(c);
+    }
+
+    while let Some(&(next_position, next)) = chars.peek() {
+        if MODIFIERS.contains(&next) {
+            symbol.push(next);
+            chars.next();
+        } else if TIE_BARS.contains(&next) && !joined {
+            chars.next();
+            while let Some(&(_, m)) = chars.peek() {
+                if !MODIFIERS.contains(&m) {
+                    break;
+                }
+                symbol.push(m);
+                chars.next();
+            }
+            let (_, connected) = chars.next().ok_or(next_position)?;
+            symbol.push('\u{0361}');
+            symbol.push(connected);
+            joined = true;
+        } else {
+            break;
+        }
+    }
+    Ok(symbol)
 }
 
+// Split a word description into syllables of symbols, each paired with the
+// char index at which it starts in the word description. The first symbol of
+// each syllable is its normalized (1..4) stress symbol, which is
+// default_stress when the first syllable has no stress symbol.
 fn split_word_desc(
+    accent: fn(&str) -> Option<Phoneme>,
     word_desc: &str,
-) -> Result<Vec<Vec<String>>, WordConstructorError> {
-    let mut symbol_iter = word_desc.chars().peekable();
+    default_stress: char,
+) -> Result<Vec<Vec<(String, usize)>>, WordConstructorError> {
+    let mut symbol_iter = word_desc.chars().enumerate().peekable();
     let mut syllables_as_symbols = Vec::new();
-    let mut current_syllable: Vec<String> = Vec::new();
+    let mut current_syllable: Vec<(String, usize)> = Vec::new();
+
+    // A syllable break followed by an explicit stress symbol is redundant;
+    // skip the break so that the stress symbol sets the syllable's stress
+    let is_stress_symbol = |c: char| "ˈˌ1234".contains(c);
+    let mut leading = word_desc.chars();
+    if leading.next() == Some('.')
+        && leading.next().is_some_and(is_stress_symbol)
+    {
+        symbol_iter.next();
+    }
 
     // Enforce stress symbol on first syllable
     // If a stress symbol is already present, advance the iterator
     // Normalize stress symbol to (1..4)
     let first_stress_symbol = symbol_iter
         .peek()
-        .copied()
+        .map(|&(_, c)| c)
         .map(|c| {
             if "1234".contains(c) {
                 symbol_iter.next();
@@ -131,15 +601,22 @@ fn split_word_desc(
                     _ => '3',
                 }
             } else {
-                '3'
+                default_stress
             }
         })
         .ok_or_else(|| {
             WordConstructorError::new("BadSylStructure: empty string")
         })?;
-    current_syllable.push(String::from(first_stress_symbol));
+    current_syllable.push((String::from(first_stress_symbol), 0));
 
-    while let Some(current) = symbol_iter.next() {
+    while let Some((position, current)) = symbol_iter.next() {
+        if current == '.'
+            && symbol_iter
+                .peek()
+                .is_some_and(|&(_, next)| is_stress_symbol(next))
+        {
+            continue;
+        }
         let mut current_symbol = String::from(current);
 
         // stress symbols
@@ -161,26 +638,33 @@ fn split_word_desc(
             current_syllable = Vec::new();
         }
 
-        // multi-character symbols
-        match symbol_iter.peek().copied() {
-            // u/0361 connector symbol (a͡ʊ)
-            Some('\u{0361}') => {
-                let connector = symbol_iter.next().unwrap();
-                let connected_symbol = symbol_iter.next().ok_or_else(|| {
-                    WordConstructorError::new(
-                        "BadWordDesc: connector u/0361 given without following symbol")})?;
-                current_symbol.push(connector);
-                current_symbol.push(connected_symbol);
-            }
-            //rhotic symbol (ə˞)
-            Some('\u{02DE}') => {
-                let rhotic_symbol = symbol_iter.next().unwrap();
-                current_symbol.push(rhotic_symbol);
+        // multi-character symbols (t͡ʃ, ə˞)
+        if !new_syllable_flag {
+            current_symbol = read_symbol((position, current), &mut symbol_iter)
+                .map_err(|connector_position| {
+                    WordConstructorError::at(
+                        "BadWordDesc: connector u/0361 given without following symbol",
+                        connector_position,
+                    )
+                })?;
+        }
+
+        // two symbols written without a connector (tʃ), when the accent
+        // recognizes the pair as a single phoneme
+        match symbol_iter.peek().map(|&(_, c)| c) {
+            Some(next)
+                if !new_syllable_flag
+                    && current_symbol.chars().count() == 1
+                    && !"ˈˌ.1234".contains(next)
+                    && accent(&format!("{}{}", current_symbol, next))
+                        .is_some() =>
+            {
+                current_symbol.push(symbol_iter.next().unwrap().1);
             }
             _ => {}
         }
 
-        current_syllable.push(current_symbol);
+        current_syllable.push((current_symbol, position));
     } // while: end symbol iteration
 
     //push final syllable
@@ -195,22 +679,44 @@ fn split_word_desc(
 /// description. The resolution to all of the errors is "examine your input string for errors", so
 /// it doesn't feel sensible to differentiate the errors further than by providing some guidance in
 /// the message.
-#[derive(Debug)]
+///
+/// Errors that can be traced to a particular symbol (an unknown symbol, a second vowel in a
+/// syllable, a dangling connector) also carry the char index of that symbol in the word
+/// description.
+#[derive(Debug, PartialEq, Eq)]
 pub struct WordConstructorError {
     msg: String,
+    position: Option<usize>,
 }
 
 impl WordConstructorError {
     fn new(msg: &str) -> WordConstructorError {
         WordConstructorError {
             msg: msg.to_string(),
+            position: None,
+        }
+    }
+
+    fn at(msg: &str, position: usize) -> WordConstructorError {
+        WordConstructorError {
+            msg: msg.to_string(),
+            position: Some(position),
         }
     }
+
+    /// The char index (not byte index) in the word description of the symbol
+    /// that caused the error, if the error can be traced to a single symbol.
+    pub fn position(&self) -> Option<usize> {
+        self.position
+    }
 }
 
 impl fmt::Display for WordConstructorError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.msg)
+        match self.position {
+            Some(position) => write!(f, "{} (at {})", self.msg, position),
+            None => write!(f, "{}", self.msg),
+        }
     }
 }
 
@@ -234,7 +740,12 @@ mod tests {
             "t" => Some(mock_phon_m('t')),
             "s" => Some(mock_phon_m('s')),
             "i" => Some(mock_phon_m('i')),
+            "a" => Some(mock_phon_m('a')),
             "ə˞" => Some(mock_phon_m('ɚ')),
+            "p" => Some(mock_phon_m('p')),
+            "t͡ʃ" | "tʃ" => Some(mock_phon_d('t', 'ʃ')),
+            "n̩" => Some(mock_phon_syllabic('n')),
+            "l̩" => Some(mock_phon_syllabic('l')),
             _ => None,
         }
     }
@@ -243,6 +754,13 @@ mod tests {
         Phoneme::Monosegment(mock_seg(sym))
     }
 
+    fn mock_phon_syllabic(sym: char) -> Phoneme {
+        let mut seg = mock_seg(sym);
+        seg.root_features.syllabic = features::BinaryFeature::Marked;
+        seg.root_features.consonantal = features::BinaryFeature::Marked;
+        Phoneme::Monosegment(seg)
+    }
+
     fn mock_phon_d(sym1: char, sym2: char) -> Phoneme {
         Phoneme::Disegment(mock_seg(sym1), mock_seg(sym2))
     }
@@ -253,7 +771,7 @@ mod tests {
             symbol: sym,
             root_features: features::RootFeatures {
                 // mark vowels as +syllabic
-                syllabic: if "ɛɚʊoi".contains(sym) {
+                syllabic: if "ɛɚʊoia".contains(sym) {
                     features::BinaryFeature::Marked
                 } else {
                     features::BinaryFeature::Unmarked
@@ -268,7 +786,7 @@ mod tests {
     #[test]
     //testing multiple syllables, breve-connected symbols, syllable break marker, only onsets
     fn test_from_accent() -> Result<(), WordConstructorError> {
-        println!("{:?}", split_word_desc("ˈhɛ.lo͡ʊ"));
+        println!("{:?}", split_word_desc(mock_accent, "ˈhɛ.lo͡ʊ", '3'));
         assert_eq!(
             from_accent(mock_accent, "ˈhɛ.lo͡ʊ")?,
             Word::from(vec![
@@ -277,12 +795,14 @@ mod tests {
                     nucleus: mock_phon_m('ɛ'),
                     coda: vec![],
                     stress: Some(Stress::Stressed),
+                    tone: None,
                 },
                 Syllable {
                     onset: vec![mock_phon_m('l')],
                     nucleus: mock_phon_d('o', 'ʊ'),
                     coda: vec![],
                     stress: Some(Stress::Unstressed),
+                    tone: None,
                 }
             ])
         );
@@ -292,7 +812,7 @@ mod tests {
     #[test]
     //testing single syl, no stress, both onset and codas
     fn test_from_accent_single_syl() -> Result<(), WordConstructorError> {
-        println!("{:?}", split_word_desc("tɛst"));
+        println!("{:?}", split_word_desc(mock_accent, "tɛst", '3'));
         assert_eq!(
             from_accent(mock_accent, "tɛst")?,
             Word::from(vec![Syllable {
@@ -300,6 +820,7 @@ mod tests {
                 nucleus: mock_phon_m('ɛ'),
                 coda: vec![mock_phon_m('s'), mock_phon_m('t')],
                 stress: None,
+                tone: None,
             }])
         );
         Ok(())
@@ -308,7 +829,7 @@ mod tests {
     #[test]
     //testing use of numbered stress
     fn test_from_accent_numbered_stress() -> Result<(), WordConstructorError> {
-        println!("{:?}", split_word_desc("2ti4tə˞"));
+        println!("{:?}", split_word_desc(mock_accent, "2ti4tə˞", '3'));
         assert_eq!(
             from_accent(mock_accent, "2ti4tə˞")?,
             Word::from(vec![
@@ -317,15 +838,372 @@ mod tests {
                     nucleus: mock_phon_m('i'),
                     coda: vec![],
                     stress: Some(Stress::SecondaryStress),
+                    tone: None,
+                },
+                Syllable {
+                    onset: vec![mock_phon_m('t')],
+                    nucleus: mock_phon_m('ɚ'),
+                    coda: vec![],
+                    stress: Some(Stress::ReducedStress),
+                    tone: None,
+                }
+            ])
+        );
+        Ok(())
+    }
+
+    #[test]
+    //testing syllable breaks mixed with numbered stress
+    fn test_from_accent_break_with_numbered_stress(
+    ) -> Result<(), WordConstructorError> {
+        assert_eq!(
+            from_accent(mock_accent, "ˈti.4tə˞.ti")?,
+            Word::from(vec![
+                Syllable {
+                    onset: vec![mock_phon_m('t')],
+                    nucleus: mock_phon_m('i'),
+                    coda: vec![],
+                    stress: Some(Stress::Stressed),
+                    tone: None,
                 },
                 Syllable {
                     onset: vec![mock_phon_m('t')],
                     nucleus: mock_phon_m('ɚ'),
                     coda: vec![],
                     stress: Some(Stress::ReducedStress),
+                    tone: None,
+                },
+                Syllable {
+                    onset: vec![mock_phon_m('t')],
+                    nucleus: mock_phon_m('i'),
+                    coda: vec![],
+                    stress: Some(Stress::Unstressed),
+                    tone: None,
+                }
+            ])
+        );
+        assert_eq!(
+            from_accent(mock_accent, ".2ti.4tə˞")?,
+            from_accent(mock_accent, "2ti4tə˞")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    //testing syllabic consonants as nuclei
+    fn test_from_accent_syllabic_consonant() -> Result<(), WordConstructorError>
+    {
+        assert_eq!(
+            from_accent(mock_accent, "ˈhɛ.tn̩")?,
+            Word::from(vec![
+                Syllable {
+                    onset: vec![mock_phon_m('h')],
+                    nucleus: mock_phon_m('ɛ'),
+                    coda: vec![],
+                    stress: Some(Stress::Stressed),
+                    tone: None,
+                },
+                Syllable {
+                    onset: vec![mock_phon_m('t')],
+                    nucleus: mock_phon_syllabic('n'),
+                    coda: vec![],
+                    stress: Some(Stress::Unstressed),
+                    tone: None,
                 }
             ])
         );
+        assert_eq!(
+            from_accent(mock_accent, "ˈhɛ.tl̩")?
+                .iter()
+                .nth(1)
+                .unwrap()
+                .nucleus,
+            mock_phon_syllabic('l')
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_accent_syllabic_n() -> Result<(), WordConstructorError> {
+        use crate::builders::consonants::*;
+        use crate::builders::SegmentBuilder;
+
+        fn accent(s: &str) -> Option<Phoneme> {
+            match s {
+                "n̩" => Some(Phoneme::Monosegment(SegmentBuilder::consonant(
+                    &[vd, alveolar, nasal, syllabic],
+                    'n',
+                ))),
+                _ => genam::phoneme(s),
+            }
+        }
+
+        let n = accent("n̩").unwrap();
+        assert!(feature_classes::is_syllabic(n));
+        assert!(!feature_classes::is_vowel(n));
+        let word = from_accent(accent, "ˈbʌ.tn̩")?;
+        let syls = word.syllables();
+        assert_eq!(syls[1].onset, vec![genam::phoneme("t").unwrap()]);
+        assert_eq!(syls[1].nucleus, n);
+        assert!(syls[1].coda.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    //testing tone letters and tone diacritics
+    fn test_from_accent_tone() -> Result<(), WordConstructorError> {
+        let word = from_accent(mock_accent, "ti˧˥.ti\u{0300}")?;
+        let tones: Vec<Option<Tone>> =
+            word.iter().map(|syl| syl.tone).collect();
+        assert_eq!(tones, vec![Some(Tone::Rising), Some(Tone::Low)]);
+        assert_eq!(
+            from_accent(mock_accent, "ti")?.iter().next().unwrap().tone,
+            None
+        );
+        assert!(from_accent(mock_accent, "ti˥˩˥˩").is_err());
+        Ok(())
+    }
+
+    #[test]
+    //testing an affricate written without a connector
+    fn test_from_accent_untied_affricate() -> Result<(), WordConstructorError> {
+        assert_eq!(
+            from_accent(mock_accent, "tʃip")?,
+            Word::from(vec![Syllable {
+                onset: vec![mock_phon_d('t', 'ʃ')],
+                nucleus: mock_phon_m('i'),
+                coda: vec![mock_phon_m('p')],
+                stress: None,
+                tone: None,
+            }])
+        );
+        Ok(())
+    }
+
+    #[test]
+    //testing two adjacent vowels in one syllable, with and without merging
+    fn test_from_accent_with_merge_adjacent_vowels(
+    ) -> Result<(), WordConstructorError> {
+        assert!(from_accent(mock_accent, "hai").is_err());
+        let opts = ParseOptions {
+            merge_adjacent_vowels: true,
+            ..ParseOptions::default()
+        };
+        assert_eq!(
+            from_accent_with(mock_accent, "hai", opts)?,
+            Word::from(vec![Syllable {
+                onset: vec![mock_phon_m('h')],
+                nucleus: mock_phon_d('a', 'i'),
+                coda: vec![],
+                stress: None,
+                tone: None,
+            }])
+        );
+        assert!(from_accent_with(mock_accent, "hais", opts).is_ok());
+        assert!(from_accent_with(mock_accent, "hasi", opts).is_err());
+        assert!(from_accent_with(mock_accent, "haio͡ʊ", opts).is_err());
+        assert_eq!(
+            from_accent_with(mock_accent, "ha.i", opts)?,
+            from_accent(mock_accent, "ha.i")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    //testing ARPAbet transcriptions, read as GenAm
+    fn test_from_arpabet() -> Result<(), WordConstructorError> {
+        assert_eq!(from_arpabet("K AE1 T")?, genam::word("kæt")?);
+        assert_eq!(from_arpabet("k ae1 t")?, genam::word("kæt")?);
+        assert_eq!(from_arpabet("AE1 K R AH0")?, genam::word("ˈæk.ɹə")?);
+        assert_eq!(
+            from_arpabet("AE1 K R AH0 B AE2 T")?,
+            genam::word("ˈæk.ɹə.ˌbæt")?
+        );
+        assert_eq!(from_arpabet("S IH1 NG ER0")?, genam::word("ˈsɪŋ.ə˞")?);
+        Ok(())
+    }
+
+    #[test]
+    //testing rejected ARPAbet transcriptions
+    fn test_from_arpabet_errors() {
+        assert_eq!(from_arpabet("K XX1 T").unwrap_err().position(), Some(2));
+        assert!(from_arpabet("K AE T").is_err());
+        assert!(from_arpabet("K1 AE1 T").is_err());
+        assert!(from_arpabet("K AE5 T").is_err());
+        assert!(from_arpabet("K T").is_err());
+        assert!(from_arpabet("").is_err());
+    }
+
+    #[test]
+    //testing the stress given to an unmarked first syllable
+    fn test_from_accent_with_default() -> Result<(), WordConstructorError> {
+        let w =
+            from_accent_with_default(mock_accent, "hɛ.lo͡ʊ", Stress::Stressed)?;
+        assert_eq!(w, from_accent(mock_accent, "ˈhɛ.lo͡ʊ")?);
+        assert_eq!(w.syllables()[0].stress, Some(Stress::Stressed));
+        // an explicit mark is kept
+        for desc in &["ˌhɛ.lo͡ʊ", "2hɛ.lo͡ʊ"] {
+            let w =
+                from_accent_with_default(mock_accent, desc, Stress::Stressed)?;
+            assert_eq!(w.syllables()[0].stress, Some(Stress::SecondaryStress));
+        }
+        // a leading syllable break marks the first syllable Unstressed
+        assert_eq!(
+            from_accent_with_default(mock_accent, ".hɛˈlo͡ʊ", Stress::Stressed)?,
+            from_accent(mock_accent, "hɛˈlo͡ʊ")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    //testing both tie bars, written between or before the joined symbols
+    fn test_from_accent_tie_bars() -> Result<(), WordConstructorError> {
+        let expected = from_accent(mock_accent, "t͡ʃip")?;
+        assert_eq!(expected.syllables()[0].onset, vec![mock_phon_d('t', 'ʃ')]);
+        assert_eq!(from_accent(mock_accent, "t͜ʃip")?, expected);
+        assert_eq!(from_accent(mock_accent, "͡tʃip")?, expected);
+        assert_eq!(from_accent(mock_accent, "͜tʃip")?, expected);
+        assert_eq!(
+            from_accent(mock_accent, "tit͜").unwrap_err().position(),
+            Some(3)
+        );
+        assert_eq!(tokenize_ipa("t͜ʃo͜ʊ"), vec!["t͡ʃ", "o͡ʊ"]);
+        // the syllabic mark (U+0329) before and after the tie bar (U+0361)
+        assert_eq!(
+            tokenize_ipa("n\u{0329}\u{0361}t"),
+            vec!["n\u{0329}\u{0361}t"]
+        );
+        assert_eq!(
+            tokenize_ipa("n\u{0361}\u{0329}t"),
+            vec!["n\u{0329}\u{0361}t"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    //testing that GenAm words survive rendering and parsing again
+    fn test_roundtrip_ok() {
+        let words = [
+            "kæt",
+            "t͡ʃɝt͡ʃ",
+            "ˈd͡ʒʌd͡ʒ",
+            "ˈhɛ.lo͡ʊ",
+            "ˈæk.ɹə.ˌbæt",
+            "ə.ˈla͡ʊ",
+            "ˌɪn.təɹ.ˈvin",
+            "ˈti.4tə",
+            "4ə.ˈbʌv",
+            "ˈθɪŋ.kɪŋ",
+            "ˈmɛ.ʒə˞",
+            "ɹɪ.ˈd͡ʒɔ͡ɪs",
+            "ˈbʌ.tə˞",
+        ];
+        for w in &words {
+            assert!(roundtrip_ok(genam::phoneme, w), "{}", w);
+        }
+        assert!(!roundtrip_ok(genam::phoneme, "kxt"));
+    }
+
+    #[test]
+    //testing accent-free tokenization of tie bars and modifiers
+    fn test_tokenize_ipa() {
+        assert_eq!(tokenize_ipa("t͡ʃə˞tʃ"), vec!["t͡ʃ", "ə˞", "t", "ʃ"]);
+        assert_eq!(tokenize_ipa("ba͡ʊn̩"), vec!["b", "a͡ʊ", "n̩"]);
+        assert_eq!(tokenize_ipa("t͡ʃ˞"), vec!["t͡ʃ˞"]);
+        assert!(tokenize_ipa("").is_empty());
+    }
+
+    #[test]
+    //testing slash and bracket wrapped transcriptions
+    fn test_from_ipa() -> Result<(), WordConstructorError> {
+        let bare = from_accent(mock_accent, "ˈhɛ.lo͡ʊ")?;
+        assert_eq!(from_ipa(mock_accent, "/ˈhɛ.lo͡ʊ/")?, bare);
+        assert_eq!(from_ipa(mock_accent, "[ˈhɛ.lo͡ʊ]")?, bare);
+        assert_eq!(from_ipa(mock_accent, "ˈhɛ.lo͡ʊ")?, bare);
+        Ok(())
+    }
+
+    #[test]
+    //testing that length marks are ignored
+    fn test_from_ipa_length_marks() -> Result<(), WordConstructorError> {
+        assert_eq!(
+            from_ipa(mock_accent, "/tiːs/")?,
+            from_accent(mock_accent, "tis")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    //testing that batch construction separates words from failures
+    fn test_from_accent_batch() -> Result<(), WordConstructorError> {
+        let (words, errors) = from_accent_batch(
+            mock_accent,
+            &[("hello", "ˈhɛ.lo͡ʊ"), ("hex", "hɛx"), ("tip", "tip")],
+        );
+        assert_eq!(words.len(), 2);
+        assert_eq!(
+            words[0],
+            ("hello".to_string(), from_accent(mock_accent, "ˈhɛ.lo͡ʊ")?)
+        );
+        assert_eq!(words[1].0, "tip");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].0, "hex");
+        assert_eq!(errors[0].1.position(), Some(2));
+        Ok(())
+    }
+
+    #[test]
+    //testing collection of unknown symbols
+    fn test_from_accent_lenient() -> Result<(), WordConstructorError> {
+        let (word, problems) = from_accent_lenient(mock_accent, "ˈhɛx.lo͡ʊ");
+        assert_eq!(word, Some(from_accent(mock_accent, "ˈhɛ.lo͡ʊ")?));
+        assert_eq!(problems, vec![ParseProblem::UnknownSymbol("x".into())]);
+        Ok(())
+    }
+
+    #[test]
+    //testing that a syllable left without a nucleus is dropped
+    fn test_from_accent_lenient_drops_syllable(
+    ) -> Result<(), WordConstructorError> {
+        let (word, problems) = from_accent_lenient(mock_accent, "ˈhɛ.lx");
+        assert_eq!(word, Some(from_accent(mock_accent, "hɛ")?));
+        assert_eq!(
+            problems,
+            vec![
+                ParseProblem::UnknownSymbol("x".into()),
+                ParseProblem::DroppedSyllable(2)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    //testing that an unknown tone is skipped, and fatal errors are kept apart
+    fn test_from_accent_lenient_tone_and_fatal(
+    ) -> Result<(), WordConstructorError> {
+        let (word, problems) = from_accent_lenient(mock_accent, "ˈhɛ˥˩˥˩.lo͡ʊ");
+        assert_eq!(word, Some(from_accent(mock_accent, "ˈhɛ.lo͡ʊ")?));
+        assert_eq!(problems, vec![ParseProblem::UnknownTone("˥˩˥˩".into())]);
+
+        let (word, problems) = from_accent_lenient(mock_accent, "hxɛi");
+        assert_eq!(word, None);
+        assert_eq!(problems.len(), 2);
+        assert_eq!(problems[0], ParseProblem::UnknownSymbol("x".into()));
+        assert!(matches!(problems[1], ParseProblem::Fatal(_)));
         Ok(())
     }
+
+    #[test]
+    //testing the reported position of an unknown symbol
+    fn test_from_accent_unknown_symbol_position() {
+        let err = from_accent(mock_accent, "ˈhɛ.lxo͡ʊ").unwrap_err();
+        assert_eq!(err.position(), Some(5));
+    }
+
+    #[test]
+    //testing the reported position of a second vowel in a syllable
+    fn test_from_accent_two_nuclei_position() {
+        let err = from_accent(mock_accent, "hɛ.lio͡ʊ").unwrap_err();
+        assert_eq!(err.position(), Some(5));
+    }
 }
@@ -0,0 +1,178 @@
+//! Prose descriptions of phonemes
+//!
+//! Describe turns the features of a phoneme into the kind of articulatory
+//! description found in an introductory phonetics text, such as "voiceless
+//! bilabial stop" or "high front tense unrounded vowel". Descriptions are built
+//! from features alone, so sounds that share all of their features (such as
+//! bilabial and labiodental sounds) share a description.
+
+use crate::feature_classes::*;
+use crate::features::accessors::*;
+use crate::features::{BinaryFeature, Segment, UnaryFeature};
+use crate::phoneme::Phoneme;
+
+/// describe provides a prose articulatory description of a phoneme.
+///
+/// Consonants are described by voicing, place, and manner ("voiceless
+/// bilabial stop"). Vowels are described by height, backness, tenseness, and
+/// rounding ("high front tense unrounded vowel"). Affricates are described as
+/// a single sound, using the voicing and place of their fricative release
+/// ("voiceless postalveolar affricate"). Other disegments, like diphthongs,
+/// join the descriptions of their two parts ("low front unrounded vowel to
+/// high front lax unrounded vowel").
+pub fn describe(p: &Phoneme) -> String {
+    match *p {
+        Phoneme::Monosegment(seg) => describe_segment(seg),
+        Phoneme::Disegment(_, seg2) if is_affricate(*p) => {
+            join(&[voicing(seg2), place(seg2), Some("affricate")])
+        }
+        Phoneme::Disegment(seg1, seg2) => {
+            format!("{} to {}", describe_segment(seg1), describe_segment(seg2))
+        }
+    }
+}
+
+fn describe_segment(seg: Segment) -> String {
+    if is_vowel(seg.into()) {
+        join(&[
+            height(seg),
+            backness(seg),
+            tenseness(seg),
+            rounding(seg),
+            nasality(seg),
+            rhoticity(seg),
+            Some("vowel"),
+        ])
+    } else {
+        join(&[voicing(seg), place(seg), manner(seg)])
+    }
+}
+
+fn join(parts: &[Option<&str>]) -> String {
+    parts
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn voicing(seg: Segment) -> Option<&'static str> {
+    match get_voice(&seg)? {
+        BinaryFeature::Marked => Some("voiced"),
+        BinaryFeature::Unmarked => Some("voiceless"),
+    }
+}
+
+fn place(seg: Segment) -> Option<&'static str> {
+    let labial = get_labial(&seg).is_some();
+    let dorsal = get_dorsal(&seg).is_some();
+    if labial && dorsal {
+        Some("labial-velar")
+    } else if labial {
+        Some("bilabial")
+    } else if let Some(anterior) = get_anterior(&seg) {
+        match anterior {
+            BinaryFeature::Marked => Some("alveolar"),
+            BinaryFeature::Unmarked => Some("postalveolar"),
+        }
+    } else if dorsal {
+        Some("velar")
+    } else if get_laryngeal(&seg).is_some() {
+        Some("glottal")
+    } else {
+        None
+    }
+}
+
+fn manner(seg: Segment) -> Option<&'static str> {
+    let p = seg.into();
+    if is_nasal(p) {
+        Some("nasal")
+    } else if is_stop(p) {
+        Some("stop")
+    } else if is_fricative(p) {
+        Some("fricative")
+    } else if is_semivowel(p) {
+        Some("glide")
+    } else if is_lateral(p) {
+        Some("lateral approximant")
+    } else if is_approximant(p) {
+        Some("approximant")
+    } else {
+        None
+    }
+}
+
+fn height(seg: Segment) -> Option<&'static str> {
+    match (get_high(&seg)?, get_low(&seg)?) {
+        (BinaryFeature::Marked, _) => Some("high"),
+        (_, BinaryFeature::Marked) => Some("low"),
+        _ => Some("mid"),
+    }
+}
+
+fn backness(seg: Segment) -> Option<&'static str> {
+    match get_back(&seg)? {
+        BinaryFeature::Marked => Some("back"),
+        BinaryFeature::Unmarked => Some("front"),
+    }
+}
+
+fn tenseness(seg: Segment) -> Option<&'static str> {
+    match get_advanced_tongue_root(&seg)? {
+        BinaryFeature::Marked => Some("tense"),
+        BinaryFeature::Unmarked => Some("lax"),
+    }
+}
+
+fn rounding(seg: Segment) -> Option<&'static str> {
+    match get_round(&seg) {
+        Some(UnaryFeature::Marked) => Some("rounded"),
+        None => Some("unrounded"),
+    }
+}
+
+fn nasality(seg: Segment) -> Option<&'static str> {
+    get_nasal(&seg).map(|_| "nasalized")
+}
+
+fn rhoticity(seg: Segment) -> Option<&'static str> {
+    get_rhotic(&seg).map(|_| "r-colored")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accents::genam;
+
+    fn describe_symbol(s: &str) -> String {
+        describe(&genam::phoneme(s).unwrap())
+    }
+
+    #[test]
+    fn test_describe_consonant() {
+        assert_eq!(describe_symbol("p"), "voiceless bilabial stop");
+        assert_eq!(describe_symbol("z"), "voiced alveolar fricative");
+        assert_eq!(describe_symbol("ŋ"), "voiced velar nasal");
+    }
+
+    #[test]
+    fn test_describe_vowel() {
+        assert_eq!(describe_symbol("i"), "high front tense unrounded vowel");
+        assert_eq!(describe_symbol("ʊ"), "high back lax rounded vowel");
+    }
+
+    #[test]
+    fn test_describe_affricate() {
+        assert_eq!(describe_symbol("t͡ʃ"), "voiceless postalveolar affricate");
+    }
+
+    #[test]
+    fn test_describe_diphthong() {
+        assert_eq!(
+            describe_symbol("a͡ɪ"),
+            "low front unrounded vowel to high front lax unrounded vowel"
+        );
+    }
+}
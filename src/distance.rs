@@ -0,0 +1,62 @@
+//! Distances between sequences of phonemes
+//!
+//! Distance provides symbol-level measures of how different two sequences of
+//! phonemes are. Phonemes are compared only for equality here, so any two
+//! different phonemes are equally far apart regardless of the features they
+//! share.
+
+use crate::phoneme::Phoneme;
+
+/// phoneme_edit_distance counts the fewest insertions, deletions, and
+/// substitutions of single phonemes needed to turn one sequence into the
+/// other (Levenshtein distance over phonemes).
+///
+/// Each edit costs 1. Substituting a phoneme for an identical phoneme costs 0.
+pub fn phoneme_edit_distance(a: &[Phoneme], b: &[Phoneme]) -> usize {
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, pa) in a.iter().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, pb) in b.iter().enumerate() {
+            let substitution = prev[j] + if pa == pb { 0 } else { 1 };
+            let deletion = prev[j + 1] + 1;
+            let insertion = current[j] + 1;
+            current.push(substitution.min(deletion).min(insertion));
+        }
+        prev = current;
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accents::genam::phoneme;
+
+    fn phons(ss: &[&str]) -> Vec<Phoneme> {
+        ss.iter().map(|s| phoneme(s).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_edit_distance_substitution() {
+        let a = phons(&["k", "æ", "t"]);
+        let b = phons(&["k", "ʊ", "t"]);
+        assert_eq!(phoneme_edit_distance(&a, &b), 1);
+    }
+
+    #[test]
+    fn test_edit_distance_transposition() {
+        let a = phons(&["k", "æ", "t"]);
+        let b = phons(&["æ", "k", "t"]);
+        assert_eq!(phoneme_edit_distance(&a, &b), 2);
+    }
+
+    #[test]
+    fn test_edit_distance_empty() {
+        let a = phons(&["k", "æ", "t"]);
+        assert_eq!(phoneme_edit_distance(&a, &[]), 3);
+        assert_eq!(phoneme_edit_distance(&[], &a), 3);
+        assert_eq!(phoneme_edit_distance(&a, &a), 0);
+    }
+}
@@ -10,59 +10,107 @@ use crate::features::accessors::*;
 use crate::features::{BinaryFeature, Segment, UnaryFeature};
 use crate::phoneme::Phoneme;
 
-/// A vowel is any phoneme that goes in the nucleus of a syllable. They are marked +syllabic.
+/// A syllabic phoneme is any phoneme that can go in the nucleus of a syllable. They are marked
+/// +syllabic.
+///
+/// This includes both vowels and syllabic consonants ('n̩', 'l̩'). Use is_syllabic to find the
+/// nucleus of a syllable, and [is_vowel] to ask about vowel quality.
+pub fn is_syllabic(p: Phoneme) -> bool {
+    any_segment(p, |seg| get_syllabic(&seg) == BinaryFeature::Marked)
+}
+
+/// A vowel is a phoneme with (+syllabic, -consonantal) features.
+///
+/// Syllabic consonants ('n̩', 'l̩') are +syllabic, but they keep the +consonantal of the consonant
+/// they are built from, so they are not vowels. They are [is_syllabic], and may still fill the
+/// nucleus of a syllable.
 pub fn is_vowel(p: Phoneme) -> bool {
-    any_segment(p, |seg| get_syllabic(seg) == BinaryFeature::Marked)
+    any_segment(p, |seg| vowel_segment(&seg))
 }
 
 /// A consonant is any phoneme that does not go in the nucleus of a syllable. They are marked
-/// -syllabic. This definition of a consonant is meant to contrast with the notion of a vowel, and
-/// therefore includes semivowels even though they are -consonantal.
+/// -syllabic. This definition of a consonant is meant to contrast with the notion of a syllabic
+/// phoneme, and therefore includes semivowels even though they are -consonantal, but excludes
+/// syllabic consonants.
 pub fn is_consonant(p: Phoneme) -> bool {
-    !is_vowel(p)
+    !is_syllabic(p)
 }
 
 /// A semivowel is a phoneme with (-consonantal, -syllabic) features.
 pub fn is_semivowel(p: Phoneme) -> bool {
     any_segment(p, |seg| {
-        get_syllabic(seg) == BinaryFeature::Unmarked
-            && get_consonantal(seg) == BinaryFeature::Unmarked
+        get_syllabic(&seg) == BinaryFeature::Unmarked
+            && get_consonantal(&seg) == BinaryFeature::Unmarked
     })
 }
 
-/// A voiced phoneme is +voiced
-pub fn is_voiced(p: Phoneme) -> bool {
+/// A glide is a phoneme with (-consonantal, +sonorant, -syllabic, +continuant) features, the
+/// profile given by the `glide` builder: 'j', 'w', 'ʍ'.
+///
+/// Glides are a subset of semivowels. [is_semivowel] only asks for (-consonantal, -syllabic), so
+/// it also admits segments with no manner features at all, while is_glide requires the sonorant
+/// continuant manner of a glide. Liquids ('l', 'ɹ') are approximants but not glides, because they
+/// are +consonantal.
+pub fn is_glide(p: Phoneme) -> bool {
     any_segment(p, |seg| {
-        get_voice(seg).map_or(false, |voice| voice == BinaryFeature::Marked)
+        get_consonantal(&seg) == BinaryFeature::Unmarked
+            && get_sonorant(&seg) == BinaryFeature::Marked
+            && get_syllabic(&seg) == BinaryFeature::Unmarked
+            && get_continuant(&seg) == Some(BinaryFeature::Marked)
     })
 }
 
+/// An obstruent is a phoneme with (-sonorant) features: stops, fricatives, and affricates. A
+/// disegment is an obstruent if either of its segments is, so affricates are obstruents.
+pub fn is_obstruent(p: Phoneme) -> bool {
+    any_segment(p, |seg| get_sonorant(&seg) == BinaryFeature::Unmarked)
+}
+
+/// A sonorant is a phoneme with (+sonorant) features: nasals, liquids, glides, and vowels. A
+/// disegment is a sonorant only if both of its segments are, so diphthongs are sonorants. Every
+/// phoneme is either a sonorant or an [obstruent](is_obstruent).
+pub fn is_sonorant(p: Phoneme) -> bool {
+    !is_obstruent(p)
+}
+
+/// A voiced phoneme is +voiced
+///
+/// Voicing is a three-way distinction: a phoneme may be explicitly voiced (+voice), explicitly
+/// voiceless (-voice, see [is_voiceless]), or have no voice specification at all. A phoneme with
+/// no voice specification is neither voiced nor voiceless, so `!is_voiced(p)` does not mean that p
+/// is voiceless.
+pub fn is_voiced(p: Phoneme) -> bool {
+    any_segment(p, |seg| get_voice(&seg) == Some(BinaryFeature::Marked))
+}
+
+/// A voiceless phoneme is explicitly -voice. A phoneme with no voice specification is not
+/// voiceless (see [is_voiced]).
+pub fn is_voiceless(p: Phoneme) -> bool {
+    any_segment(p, |seg| get_voice(&seg) == Some(BinaryFeature::Unmarked))
+}
+
 /// A stop is a phoneme with (-sonorant, -continuant) features
 pub fn is_stop(p: Phoneme) -> bool {
     any_segment(p, |seg| {
-        get_sonorant(seg) == BinaryFeature::Unmarked
-            && get_continuant(seg).map_or(false, |continuant| {
-                continuant == BinaryFeature::Unmarked
-            })
+        get_sonorant(&seg) == BinaryFeature::Unmarked
+            && get_continuant(&seg) == Some(BinaryFeature::Unmarked)
     })
 }
 
 /// A fricative is a phoneme with (-sonorant, +continuant) features
 pub fn is_fricative(p: Phoneme) -> bool {
     any_segment(p, |seg| {
-        get_sonorant(seg) == BinaryFeature::Unmarked
-            && get_continuant(seg)
-                .map_or(false, |continuant| continuant == BinaryFeature::Marked)
+        get_sonorant(&seg) == BinaryFeature::Unmarked
+            && get_continuant(&seg) == Some(BinaryFeature::Marked)
     })
 }
 
 /// An approximant is a phoneme with (+sonorant, -syllabic, +continuant) features
 pub fn is_approximant(p: Phoneme) -> bool {
     any_segment(p, |seg| {
-        get_sonorant(seg) == BinaryFeature::Marked
-            && get_syllabic(seg) == BinaryFeature::Unmarked
-            && get_continuant(seg)
-                .map_or(false, |continuant| continuant == BinaryFeature::Marked)
+        get_sonorant(&seg) == BinaryFeature::Marked
+            && get_syllabic(&seg) == BinaryFeature::Unmarked
+            && get_continuant(&seg) == Some(BinaryFeature::Marked)
     })
 }
 
@@ -76,21 +124,71 @@ pub fn is_affricate(p: Phoneme) -> bool {
     }
 }
 
+/// A phoneme with delayed release is an affricate.
+///
+/// There is no \[delrel\] feature on segments. Delayed release is instead
+/// expressed structurally, as a stop followed by a fricative within a single
+/// disegment, so this is true exactly when [is_affricate] is true. No
+/// monosegment has delayed release.
+pub fn has_delayed_release(p: Phoneme) -> bool {
+    is_affricate(p)
+}
+
+/// The onglide of a diphthong is its first (starting) segment.
+///
+/// A diphthong is a disegment in which both segments are vowels. Monosegments and other disegments
+/// (affricates) have no onglide.
+pub fn diphthong_onglide(p: &Phoneme) -> Option<Segment> {
+    diphthong_segments(p).map(|(onglide, _)| onglide)
+}
+
+/// The offglide of a diphthong is its second (ending) segment.
+///
+/// See [diphthong_onglide] for which phonemes count as diphthongs.
+pub fn diphthong_offglide(p: &Phoneme) -> Option<Segment> {
+    diphthong_segments(p).map(|(_, offglide)| offglide)
+}
+
+fn diphthong_segments(p: &Phoneme) -> Option<(Segment, Segment)> {
+    match *p {
+        Phoneme::Disegment(seg1, seg2)
+            if is_vowel(seg1.into()) && is_vowel(seg2.into()) =>
+        {
+            Some((seg1, seg2))
+        }
+        _ => None,
+    }
+}
+
 /// A nasal is a phoneme with the nasal feature
 pub fn is_nasal(p: Phoneme) -> bool {
+    any_segment(p, |seg| get_nasal(&seg) == Some(UnaryFeature::Marked))
+}
+
+/// A nasal vowel is a phoneme with (+syllabic, -consonantal, nasal) features. Syllabic nasals
+/// ('n̩') are not nasal vowels.
+pub fn is_nasal_vowel(p: Phoneme) -> bool {
     any_segment(p, |seg| {
-        seg.autosegmental_features
-            .nasal
-            .map_or(false, |nasal| nasal == UnaryFeature::Marked)
+        vowel_segment(&seg) && get_nasal(&seg) == Some(UnaryFeature::Marked)
     })
 }
 
 /// A lateral is a phoneme with the lateral feature
 pub fn is_lateral(p: Phoneme) -> bool {
     any_segment(p, |seg| {
-        seg.autosegmental_features
-            .lateral
-            .map_or(false, |lateral| lateral == UnaryFeature::Marked)
+        seg.autosegmental_features.lateral == Some(UnaryFeature::Marked)
+    })
+}
+
+/// A rhotic phoneme is a phoneme with the rhotic feature, such as 'ɹ' or 'ɚ'
+pub fn is_rhotic(p: Phoneme) -> bool {
+    any_segment(p, |seg| get_rhotic(&seg) == Some(UnaryFeature::Marked))
+}
+
+/// A rhotic (r-colored) vowel is a phoneme with (+syllabic, -consonantal, rhotic) features
+pub fn is_rhotic_vowel(p: Phoneme) -> bool {
+    any_segment(p, |seg| {
+        vowel_segment(&seg) && get_rhotic(&seg) == Some(UnaryFeature::Marked)
     })
 }
 
@@ -98,7 +196,7 @@ pub fn is_lateral(p: Phoneme) -> bool {
 pub fn is_high_vowel(p: Phoneme) -> bool {
     any_segment(p, |seg| {
         seg.root_features.syllabic == BinaryFeature::Marked
-            && get_high(seg).map_or(false, |high| high == BinaryFeature::Marked)
+            && get_high(&seg) == Some(BinaryFeature::Marked)
     })
 }
 
@@ -106,7 +204,7 @@ pub fn is_high_vowel(p: Phoneme) -> bool {
 pub fn is_low_vowel(p: Phoneme) -> bool {
     any_segment(p, |seg| {
         seg.root_features.syllabic == BinaryFeature::Marked
-            && get_low(seg).map_or(false, |low| low == BinaryFeature::Marked)
+            && get_low(&seg) == Some(BinaryFeature::Marked)
     })
 }
 
@@ -114,15 +212,238 @@ pub fn is_low_vowel(p: Phoneme) -> bool {
 pub fn is_mid_vowel(p: Phoneme) -> bool {
     any_segment(p, |seg| {
         seg.root_features.syllabic == BinaryFeature::Marked
-            && get_high(seg)
-                .map_or(false, |high| high == BinaryFeature::Unmarked)
-            && get_low(seg).map_or(false, |low| low == BinaryFeature::Unmarked)
+            && get_high(&seg) == Some(BinaryFeature::Unmarked)
+            && get_low(&seg) == Some(BinaryFeature::Unmarked)
     })
 }
 
+/// A labial is a phoneme articulated with the lips (a labial place node): 'p', 'f', 'w', 'u'
+pub fn is_labial(p: Phoneme) -> bool {
+    any_segment(p, |seg| get_labial(&seg).is_some())
+}
+
+/// A coronal is a phoneme articulated with the tongue tip or blade (a coronal place node): 't',
+/// 's', 'ʃ', 'ɹ'
+pub fn is_coronal(p: Phoneme) -> bool {
+    any_segment(p, |seg| get_coronal(&seg).is_some())
+}
+
+/// A dorsal is a phoneme articulated with the tongue body (a dorsal place node): 'k', 'ŋ', 'j',
+/// and every vowel
+pub fn is_dorsal(p: Phoneme) -> bool {
+    any_segment(p, |seg| get_dorsal(&seg).is_some())
+}
+
+/// A natural class test, such as [is_vowel]
+pub type NaturalClass = fn(Phoneme) -> bool;
+
+/// Every natural class test in this module, paired with the name of its class.
+///
+/// Tests on parts of a phoneme (such as [diphthong_onglide]) and [has_delayed_release], which is
+/// the same class as [is_affricate], are not included.
+pub const CLASSES: &[(&str, NaturalClass)] = &[
+    ("syllabic", is_syllabic),
+    ("vowel", is_vowel),
+    ("consonant", is_consonant),
+    ("semivowel", is_semivowel),
+    ("glide", is_glide),
+    ("obstruent", is_obstruent),
+    ("sonorant", is_sonorant),
+    ("voiced", is_voiced),
+    ("voiceless", is_voiceless),
+    ("stop", is_stop),
+    ("fricative", is_fricative),
+    ("approximant", is_approximant),
+    ("affricate", is_affricate),
+    ("nasal", is_nasal),
+    ("nasal vowel", is_nasal_vowel),
+    ("lateral", is_lateral),
+    ("rhotic", is_rhotic),
+    ("rhotic vowel", is_rhotic_vowel),
+    ("high vowel", is_high_vowel),
+    ("low vowel", is_low_vowel),
+    ("mid vowel", is_mid_vowel),
+    ("labial", is_labial),
+    ("coronal", is_coronal),
+    ("dorsal", is_dorsal),
+];
+
+/// The names of every natural class in [CLASSES] that a phoneme belongs to, in the order they are
+/// listed there.
+///
+/// This is meant for teaching and debugging, to see at a glance how a phoneme is classified.
+pub fn classes_of(p: Phoneme) -> Vec<&'static str> {
+    CLASSES
+        .iter()
+        .filter(|(_, is_member)| is_member(p))
+        .map(|(name, _)| *name)
+        .collect()
+}
+
+fn vowel_segment(seg: &Segment) -> bool {
+    get_syllabic(seg) == BinaryFeature::Marked
+        && get_consonantal(seg) == BinaryFeature::Unmarked
+}
+
 fn any_segment(p: Phoneme, f: fn(Segment) -> bool) -> bool {
     match p {
         Phoneme::Monosegment(seg) => f(seg),
         Phoneme::Disegment(seg1, seg2) => f(seg1) || f(seg2),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accents::genam::phoneme;
+    use crate::builders::vowels::*;
+    use crate::builders::SegmentBuilder;
+
+    fn phon(s: &str) -> Phoneme {
+        phoneme(s).unwrap()
+    }
+
+    #[test]
+    fn test_diphthong_glides() {
+        let ey = phon("e͡ɪ");
+        let onglide = diphthong_onglide(&ey).unwrap();
+        let offglide = diphthong_offglide(&ey).unwrap();
+        assert_eq!(onglide.symbol, 'e');
+        assert!(is_mid_vowel(onglide.into()));
+        assert_eq!(get_back(&onglide), Some(BinaryFeature::Unmarked));
+        assert_eq!(offglide.symbol, 'ɪ');
+        assert!(is_high_vowel(offglide.into()));
+        assert_eq!(get_back(&offglide), Some(BinaryFeature::Unmarked));
+
+        assert_eq!(diphthong_onglide(&phon("ɛ")), None);
+        assert_eq!(diphthong_offglide(&phon("t͡ʃ")), None);
+    }
+
+    #[test]
+    fn test_is_syllabic() {
+        use crate::builders::consonants::*;
+        use crate::builders::SegmentBuilder;
+
+        let syllabic_n = Phoneme::Monosegment(SegmentBuilder::consonant(
+            &[vd, alveolar, nasal, syllabic],
+            'n',
+        ));
+        assert!(is_syllabic(syllabic_n));
+        assert!(!is_vowel(syllabic_n));
+        assert!(!is_consonant(syllabic_n));
+        assert!(is_syllabic(phon("i")) && is_vowel(phon("i")));
+        assert!(!is_syllabic(phon("n")) && is_consonant(phon("n")));
+    }
+
+    #[test]
+    fn test_is_glide() {
+        for s in &["j", "w", "ʍ"] {
+            assert!(is_glide(phon(s)), "{}", s);
+            assert!(is_semivowel(phon(s)), "{}", s);
+        }
+        for s in &["l", "ɹ"] {
+            assert!(!is_glide(phon(s)), "{}", s);
+            assert!(is_approximant(phon(s)), "{}", s);
+        }
+        assert!(!is_glide(phon("i")));
+        let bare = Phoneme::Monosegment(SegmentBuilder::segment(&[], 'X'));
+        assert!(is_semivowel(bare));
+        assert!(!is_glide(bare));
+    }
+
+    #[test]
+    fn test_place_classes() {
+        assert!(is_labial(phon("p")) && !is_coronal(phon("p")));
+        assert!(is_coronal(phon("ʃ")) && !is_dorsal(phon("ʃ")));
+        assert!(is_dorsal(phon("k")) && is_dorsal(phon("ɑ")));
+        assert!(is_labial(phon("w")) && is_dorsal(phon("w")));
+    }
+
+    #[test]
+    fn test_classes_of() {
+        let m = classes_of(phon("m"));
+        for class in &["consonant", "sonorant", "nasal", "voiced", "labial"] {
+            assert!(m.contains(class), "{}", class);
+        }
+        assert!(!m.contains(&"vowel"));
+        assert!(!m.contains(&"obstruent"));
+
+        let i = classes_of(phon("i"));
+        assert!(i.contains(&"vowel") && i.contains(&"high vowel"));
+    }
+
+    #[test]
+    fn test_is_voiceless() {
+        assert!(is_voiceless(phon("p")));
+        assert!(!is_voiced(phon("p")));
+        assert!(is_voiced(phon("b")));
+        assert!(!is_voiceless(phon("b")));
+        // GenAm specifies voice on its sonorant consonants
+        assert!(is_voiced(phon("m")));
+        // vowels carry no voice specification in GenAm
+        assert!(!is_voiced(phon("ɑ")));
+        assert!(!is_voiceless(phon("ɑ")));
+    }
+
+    #[test]
+    fn test_is_obstruent() {
+        for s in &["p", "d", "s", "ʒ", "h", "t͡ʃ", "d͡ʒ"] {
+            assert!(is_obstruent(phon(s)), "{}", s);
+            assert!(!is_sonorant(phon(s)), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_is_sonorant() {
+        for s in &["m", "ŋ", "l", "ɹ", "j", "w", "i", "ɑ", "a͡ɪ", "ə˞"] {
+            assert!(is_sonorant(phon(s)), "{}", s);
+            assert!(!is_obstruent(phon(s)), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_is_nasal_vowel() {
+        let nasal_a: Phoneme =
+            SegmentBuilder::vowel(&[low, back, nasalized], 'ɑ').into();
+        assert!(is_vowel(nasal_a));
+        assert!(is_nasal(nasal_a));
+        assert!(is_nasal_vowel(nasal_a));
+        assert!(!is_nasal_vowel(phon("ɑ")));
+        assert!(!is_nasal_vowel(phon("m")));
+    }
+
+    #[test]
+    fn test_is_nasal_vowel_syllabic_nasal() {
+        use crate::builders::consonants::*;
+
+        let syllabic_n: Phoneme =
+            SegmentBuilder::consonant(&[vd, alveolar, nasal, syllabic], 'n')
+                .into();
+        assert!(is_nasal(syllabic_n));
+        assert!(!is_nasal_vowel(syllabic_n));
+    }
+
+    #[test]
+    fn test_is_rhotic() {
+        assert!(is_rhotic(phon("ɜ˞")));
+        assert!(is_rhotic(phon("ɹ")));
+        assert!(!is_rhotic(phon("i")));
+    }
+
+    #[test]
+    fn test_is_rhotic_vowel() {
+        assert!(is_rhotic_vowel(phon("ɜ˞")));
+        assert!(is_rhotic_vowel(phon("ə˞")));
+        assert!(!is_rhotic_vowel(phon("ɹ")));
+        assert!(!is_rhotic_vowel(phon("i")));
+    }
+
+    #[test]
+    fn test_has_delayed_release() {
+        assert!(has_delayed_release(phon("t͡ʃ")));
+        assert!(has_delayed_release(phon("d͡ʒ")));
+        assert!(!has_delayed_release(phon("t")));
+        assert!(!has_delayed_release(phon("ʃ")));
+        assert!(!has_delayed_release(phon("a͡ɪ")));
+    }
+}
@@ -79,6 +79,10 @@
 //! [`Binary`]: enum.BinaryFeature.html
 //! [`Disegments`]: ../phoneme/enum.Phoneme.html
 
+use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
 ///A Binary Feature describes a contrastive feature.
 ///
 ///Both the markedness (+) or unmarkedness (-) of the feature can be used to
@@ -235,52 +239,514 @@ pub struct LaryngealFeatures {
     pub voice: Option<BinaryFeature>,
 }
 
+impl Segment {
+    ///Test whether another segment satisfies this segment as a template.
+    ///
+    ///Any autosegmental feature that is absent (None) in self is treated as a
+    ///wildcard, matching any value in other. Features that are present in
+    ///self must be present with the same value in other. Root features are
+    ///always specified, and so must be equal. Symbols are ignored.
+    ///
+    ///This lets an underspecified segment describe the target of a
+    ///phonological rule (ex: "any nasal") without listing every feature.
+    pub fn matches(&self, other: &Segment) -> bool {
+        let (t, o) =
+            (&self.autosegmental_features, &other.autosegmental_features);
+        self.root_features == other.root_features
+            && leaf_matches(t.nasal, o.nasal)
+            && leaf_matches(t.lateral, o.lateral)
+            && leaf_matches(t.rhotic, o.rhotic)
+            && leaf_matches(t.strident, o.strident)
+            && leaf_matches(t.continuant, o.continuant)
+            && node_matches(t.place, o.place, place_matches)
+            && node_matches(t.laryngeal, o.laryngeal, laryngeal_matches)
+    }
+
+    ///Test whether two segments have the same features, ignoring their
+    ///symbols. Two transcriptions that use different glyphs for the same
+    ///sound ('ɡ' and 'g') are feature_eq but not ==.
+    pub fn feature_eq(&self, other: &Segment) -> bool {
+        self.root_features == other.root_features
+            && self.autosegmental_features == other.autosegmental_features
+    }
+
+    ///Wrap a segment as a [FeatureKey], for use in hashed collections that
+    ///should treat feature_eq segments as the same entry.
+    pub fn feature_key(self) -> FeatureKey {
+        FeatureKey(self)
+    }
+
+    ///Merge two segments into a single segment that carries the features of
+    ///both.
+    ///
+    ///Field by field through the feature geometry, agreeing values are kept
+    ///and a feature absent (None) on one side is filled from the other. This
+    ///is the autosegmental "spreading" of a feature from one segment onto an
+    ///underspecified neighbor. The resulting segment keeps the symbol of self.
+    ///
+    ///Unification fails with a [UnificationError] when the two segments
+    ///specify conflicting values for the same feature.
+    pub fn unify(&self, other: &Segment) -> Result<Segment, UnificationError> {
+        let (a, b) = (self.root_features, other.root_features);
+        let root_features = RootFeatures {
+            consonantal: unify_root(
+                a.consonantal,
+                b.consonantal,
+                "consonantal",
+            )?,
+            sonorant: unify_root(a.sonorant, b.sonorant, "sonorant")?,
+            syllabic: unify_root(a.syllabic, b.syllabic, "syllabic")?,
+        };
+
+        let (a, b) =
+            (self.autosegmental_features, other.autosegmental_features);
+        let autosegmental_features = AutosegmentalFeatures {
+            nasal: unify_leaf(a.nasal, b.nasal, "nasal")?,
+            lateral: unify_leaf(a.lateral, b.lateral, "lateral")?,
+            rhotic: unify_leaf(a.rhotic, b.rhotic, "rhotic")?,
+            strident: unify_leaf(a.strident, b.strident, "strident")?,
+            continuant: unify_leaf(a.continuant, b.continuant, "continuant")?,
+            place: unify_node(a.place, b.place, unify_place)?,
+            laryngeal: unify_node(a.laryngeal, b.laryngeal, unify_laryngeal)?,
+        };
+
+        Ok(Segment {
+            root_features,
+            autosegmental_features,
+            symbol: self.symbol,
+        })
+    }
+}
+
+///A segment compared and hashed by its features alone, ignoring its symbol.
+///
+///Segments that are feature_eq but written with different glyphs ('ɡ' and
+///'g') are equal as keys, so a `HashSet<FeatureKey>` holds one entry per
+///distinct sound. The wrapped segment is the first one inserted.
+#[derive(Debug, Clone, Copy)]
+pub struct FeatureKey(pub Segment);
+
+impl PartialEq for FeatureKey {
+    fn eq(&self, other: &FeatureKey) -> bool {
+        self.0.feature_eq(&other.0)
+    }
+}
+
+impl Eq for FeatureKey {}
+
+impl Hash for FeatureKey {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.root_features.hash(state);
+        self.0.autosegmental_features.hash(state);
+    }
+}
+
+///Count the specified (Some) features in a segment's autosegmental tree,
+///as a measure of how specified the segment is.
+///
+///Every present node (place, labial, coronal, dorsal, pharyngeal,
+///laryngeal) and every present leaf feature beneath the root counts once.
+///Root features are always specified and are not counted, so a bare
+///template segment counts 0.
+pub fn specified_autosegmental_count(seg: &Segment) -> usize {
+    let a = seg.autosegmental_features;
+    let count = |present: &[bool]| present.iter().filter(|p| **p).count();
+
+    let mut n = count(&[
+        a.nasal.is_some(),
+        a.lateral.is_some(),
+        a.rhotic.is_some(),
+        a.strident.is_some(),
+        a.continuant.is_some(),
+    ]);
+    if let Some(place) = a.place {
+        n += 1;
+        if let Some(l) = place.labial {
+            n += 1 + count(&[l.round.is_some()]);
+        }
+        if let Some(c) = place.coronal {
+            n += 1 + count(&[c.anterior.is_some(), c.distrib.is_some()]);
+        }
+        if let Some(d) = place.dorsal {
+            n += 1 + count(&[
+                d.high.is_some(),
+                d.low.is_some(),
+                d.back.is_some(),
+            ]);
+        }
+        if let Some(p) = place.pharyngeal {
+            n += 1 + count(&[p.advanced_tongue_root.is_some()]);
+        }
+    }
+    if let Some(l) = a.laryngeal {
+        n += 1 + count(&[
+            l.voice.is_some(),
+            l.spread_glottis.is_some(),
+            l.constricted_glottis.is_some(),
+        ]);
+    }
+    n
+}
+
+///Render a segment's features as an indented tree, following the feature
+///geometry diagram in the [module docs](self). Absent (None) features and
+///nodes are omitted. Binary features are written with their sign (+voice,
+///-voice) and unary features by name alone (nasal).
+///
+///This is meant for reading and debugging segments, not for parsing.
+pub fn pretty(seg: &Segment) -> String {
+    let mut lines = vec![seg.symbol.to_string()];
+    let mut push = |depth: usize, text: &str| {
+        lines.push(format!("{}{}", "  ".repeat(depth), text))
+    };
+    let bin = |f: BinaryFeature, name: &str| match f {
+        BinaryFeature::Marked => format!("+{}", name),
+        BinaryFeature::Unmarked => format!("-{}", name),
+    };
+    let r = seg.root_features;
+    let a = seg.autosegmental_features;
+
+    push(1, &bin(r.consonantal, "consonantal"));
+    push(1, &bin(r.sonorant, "sonorant"));
+    push(1, &bin(r.syllabic, "syllabic"));
+
+    if let Some(place) = a.place {
+        push(1, "place");
+        if let Some(l) = place.labial {
+            push(2, "labial");
+            if l.round.is_some() {
+                push(3, "round");
+            }
+        }
+        if let Some(c) = place.coronal {
+            push(2, "coronal");
+            if let Some(f) = c.anterior {
+                push(3, &bin(f, "anterior"));
+            }
+            if let Some(f) = c.distrib {
+                push(3, &bin(f, "distrib"));
+            }
+        }
+        if let Some(d) = place.dorsal {
+            push(2, "dorsal");
+            if let Some(f) = d.high {
+                push(3, &bin(f, "high"));
+            }
+            if let Some(f) = d.low {
+                push(3, &bin(f, "low"));
+            }
+            if let Some(f) = d.back {
+                push(3, &bin(f, "back"));
+            }
+        }
+        if let Some(p) = place.pharyngeal {
+            push(2, "pharyngeal");
+            if let Some(f) = p.advanced_tongue_root {
+                push(3, &bin(f, "ATR"));
+            }
+        }
+    }
+    if let Some(f) = a.continuant {
+        push(1, &bin(f, "continuant"));
+    }
+    if let Some(f) = a.strident {
+        push(1, &bin(f, "strident"));
+    }
+    if a.lateral.is_some() {
+        push(1, "lateral");
+    }
+    if a.nasal.is_some() {
+        push(1, "nasal");
+    }
+    if let Some(l) = a.laryngeal {
+        push(1, "laryngeal");
+        if l.spread_glottis.is_some() {
+            push(2, "SG");
+        }
+        if l.constricted_glottis.is_some() {
+            push(2, "CG");
+        }
+        if let Some(f) = l.voice {
+            push(2, &bin(f, "voice"));
+        }
+    }
+    if a.rhotic.is_some() {
+        push(1, "rhotic");
+    }
+
+    lines.join("\n")
+}
+
+///Interpolate the midpoint between two segments, as along the trajectory of
+///a diphthong from its first segment to its second.
+///
+///Binary features cannot be averaged, so the midpoint keeps each feature on
+///which the two segments agree and leaves unspecified (None) each feature on
+///which they disagree. Between 'a' (+low, -high) and 'ɪ' (-low, +high), the
+///midpoint is unspecified for height but keeps the \[-back\] that both share.
+///
+///This is only meaningful for the vowel-space features of the dorsal node
+///(high, low, back). Other autosegmental features are kept or dropped in the
+///same way, but the midpoint of two consonants is not a sound. Root features
+///cannot be unspecified, so the midpoint takes the root features of a (for
+///two vowels, these are shared). The midpoint also keeps the symbol of a.
+pub fn interpolate(a: &Segment, b: &Segment) -> Segment {
+    let (x, y) = (a.autosegmental_features, b.autosegmental_features);
+    Segment {
+        root_features: a.root_features,
+        autosegmental_features: AutosegmentalFeatures {
+            nasal: shared_leaf(x.nasal, y.nasal),
+            lateral: shared_leaf(x.lateral, y.lateral),
+            rhotic: shared_leaf(x.rhotic, y.rhotic),
+            strident: shared_leaf(x.strident, y.strident),
+            continuant: shared_leaf(x.continuant, y.continuant),
+            place: shared_node(x.place, y.place, shared_place),
+            laryngeal: shared_node(x.laryngeal, y.laryngeal, |a, b| {
+                LaryngealFeatures {
+                    spread_glottis: shared_leaf(
+                        a.spread_glottis,
+                        b.spread_glottis,
+                    ),
+                    constricted_glottis: shared_leaf(
+                        a.constricted_glottis,
+                        b.constricted_glottis,
+                    ),
+                    voice: shared_leaf(a.voice, b.voice),
+                }
+            }),
+        },
+        symbol: a.symbol,
+    }
+}
+
+fn shared_leaf<T: PartialEq>(a: Option<T>, b: Option<T>) -> Option<T> {
+    if a == b {
+        a
+    } else {
+        None
+    }
+}
+
+fn shared_node<T>(a: Option<T>, b: Option<T>, f: fn(T, T) -> T) -> Option<T> {
+    match (a, b) {
+        (Some(a), Some(b)) => Some(f(a, b)),
+        _ => None,
+    }
+}
+
+fn shared_place(a: Place, b: Place) -> Place {
+    Place {
+        labial: shared_node(a.labial, b.labial, |a, b| LabialFeature {
+            round: shared_leaf(a.round, b.round),
+        }),
+        coronal: shared_node(a.coronal, b.coronal, |a, b| CoronalFeature {
+            anterior: shared_leaf(a.anterior, b.anterior),
+            distrib: shared_leaf(a.distrib, b.distrib),
+        }),
+        dorsal: shared_node(a.dorsal, b.dorsal, |a, b| DorsalFeature {
+            high: shared_leaf(a.high, b.high),
+            low: shared_leaf(a.low, b.low),
+            back: shared_leaf(a.back, b.back),
+        }),
+        pharyngeal: shared_node(a.pharyngeal, b.pharyngeal, |a, b| {
+            PharyngealFeature {
+                advanced_tongue_root: shared_leaf(
+                    a.advanced_tongue_root,
+                    b.advanced_tongue_root,
+                ),
+            }
+        }),
+    }
+}
+
+///An error created when two segments specify conflicting feature values.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnificationError {
+    feature: &'static str,
+}
+
+impl UnificationError {
+    ///The name of the feature whose values conflicted.
+    pub fn feature(&self) -> &'static str {
+        self.feature
+    }
+}
+
+impl fmt::Display for UnificationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ConflictingFeature: [{}] values differ", self.feature)
+    }
+}
+
+impl Error for UnificationError {}
+
+fn unify_root(
+    a: BinaryFeature,
+    b: BinaryFeature,
+    feature: &'static str,
+) -> Result<BinaryFeature, UnificationError> {
+    if a == b {
+        Ok(a)
+    } else {
+        Err(UnificationError { feature })
+    }
+}
+
+fn unify_leaf<T: PartialEq>(
+    a: Option<T>,
+    b: Option<T>,
+    feature: &'static str,
+) -> Result<Option<T>, UnificationError> {
+    match (a, b) {
+        (Some(a), Some(b)) if a != b => Err(UnificationError { feature }),
+        (Some(a), _) => Ok(Some(a)),
+        (None, b) => Ok(b),
+    }
+}
+
+fn unify_node<T>(
+    a: Option<T>,
+    b: Option<T>,
+    f: fn(T, T) -> Result<T, UnificationError>,
+) -> Result<Option<T>, UnificationError> {
+    match (a, b) {
+        (Some(a), Some(b)) => f(a, b).map(Some),
+        (Some(a), None) => Ok(Some(a)),
+        (None, b) => Ok(b),
+    }
+}
+
+fn unify_place(a: Place, b: Place) -> Result<Place, UnificationError> {
+    Ok(Place {
+        labial: unify_node(a.labial, b.labial, |a, b| {
+            Ok(LabialFeature {
+                round: unify_leaf(a.round, b.round, "round")?,
+            })
+        })?,
+        coronal: unify_node(a.coronal, b.coronal, |a, b| {
+            Ok(CoronalFeature {
+                anterior: unify_leaf(a.anterior, b.anterior, "anterior")?,
+                distrib: unify_leaf(a.distrib, b.distrib, "distrib")?,
+            })
+        })?,
+        dorsal: unify_node(a.dorsal, b.dorsal, |a, b| {
+            Ok(DorsalFeature {
+                high: unify_leaf(a.high, b.high, "high")?,
+                low: unify_leaf(a.low, b.low, "low")?,
+                back: unify_leaf(a.back, b.back, "back")?,
+            })
+        })?,
+        pharyngeal: unify_node(a.pharyngeal, b.pharyngeal, |a, b| {
+            Ok(PharyngealFeature {
+                advanced_tongue_root: unify_leaf(
+                    a.advanced_tongue_root,
+                    b.advanced_tongue_root,
+                    "ATR",
+                )?,
+            })
+        })?,
+    })
+}
+
+fn unify_laryngeal(
+    a: LaryngealFeatures,
+    b: LaryngealFeatures,
+) -> Result<LaryngealFeatures, UnificationError> {
+    Ok(LaryngealFeatures {
+        spread_glottis: unify_leaf(a.spread_glottis, b.spread_glottis, "SG")?,
+        constricted_glottis: unify_leaf(
+            a.constricted_glottis,
+            b.constricted_glottis,
+            "CG",
+        )?,
+        voice: unify_leaf(a.voice, b.voice, "voice")?,
+    })
+}
+
+fn leaf_matches<T: PartialEq>(template: Option<T>, other: Option<T>) -> bool {
+    template.is_none() || template == other
+}
+
+fn node_matches<T>(
+    template: Option<T>,
+    other: Option<T>,
+    f: fn(T, T) -> bool,
+) -> bool {
+    match (template, other) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(t), Some(o)) => f(t, o),
+    }
+}
+
+fn place_matches(t: Place, o: Place) -> bool {
+    node_matches(t.labial, o.labial, |t, o| leaf_matches(t.round, o.round))
+        && node_matches(t.coronal, o.coronal, |t, o| {
+            leaf_matches(t.anterior, o.anterior)
+                && leaf_matches(t.distrib, o.distrib)
+        })
+        && node_matches(t.dorsal, o.dorsal, |t, o| {
+            leaf_matches(t.high, o.high)
+                && leaf_matches(t.low, o.low)
+                && leaf_matches(t.back, o.back)
+        })
+        && node_matches(t.pharyngeal, o.pharyngeal, |t, o| {
+            leaf_matches(t.advanced_tongue_root, o.advanced_tongue_root)
+        })
+}
+
+fn laryngeal_matches(t: LaryngealFeatures, o: LaryngealFeatures) -> bool {
+    leaf_matches(t.spread_glottis, o.spread_glottis)
+        && leaf_matches(t.constricted_glottis, o.constricted_glottis)
+        && leaf_matches(t.voice, o.voice)
+}
+
 /// structure-blind accessors for segment features
 pub mod accessors {
     use super::*;
 
     /// structure-blind accessor for consonantal feature.
-    pub fn get_consonantal(segment: Segment) -> BinaryFeature {
+    pub fn get_consonantal(segment: &Segment) -> BinaryFeature {
         segment.root_features.consonantal
     }
 
     /// structure-blind accessor for sonorant feature.
-    pub fn get_sonorant(segment: Segment) -> BinaryFeature {
+    pub fn get_sonorant(segment: &Segment) -> BinaryFeature {
         segment.root_features.sonorant
     }
 
     /// structure-blind accessor for syllabic feature.
-    pub fn get_syllabic(segment: Segment) -> BinaryFeature {
+    pub fn get_syllabic(segment: &Segment) -> BinaryFeature {
         segment.root_features.syllabic
     }
 
     /// structure-blind accessor for continuant feature.
-    pub fn get_continuant(segment: Segment) -> Option<BinaryFeature> {
+    pub fn get_continuant(segment: &Segment) -> Option<BinaryFeature> {
         segment.autosegmental_features.continuant
     }
 
     /// structure-blind accessor for strident feature.
-    pub fn get_strident(segment: Segment) -> Option<BinaryFeature> {
+    pub fn get_strident(segment: &Segment) -> Option<BinaryFeature> {
         segment.autosegmental_features.strident
     }
 
     /// structure-blind accessor for lateral feature.
-    pub fn get_lateral(segment: Segment) -> Option<UnaryFeature> {
+    pub fn get_lateral(segment: &Segment) -> Option<UnaryFeature> {
         segment.autosegmental_features.lateral
     }
 
     /// structure-blind accessor for nasal feature.
-    pub fn get_nasal(segment: Segment) -> Option<UnaryFeature> {
+    pub fn get_nasal(segment: &Segment) -> Option<UnaryFeature> {
         segment.autosegmental_features.nasal
     }
 
     /// structure-blind accessor for laryngeal feature.
-    pub fn get_laryngeal(segment: Segment) -> Option<LaryngealFeatures> {
+    pub fn get_laryngeal(segment: &Segment) -> Option<LaryngealFeatures> {
         segment.autosegmental_features.laryngeal
     }
 
     /// structure-blind accessor for spread_glottis feature.
-    pub fn get_spread_glottis(segment: Segment) -> Option<UnaryFeature> {
+    pub fn get_spread_glottis(segment: &Segment) -> Option<UnaryFeature> {
         segment
             .autosegmental_features
             .laryngeal
@@ -288,7 +754,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for constricted_glottis feature.
-    pub fn get_constricted_glottis(segment: Segment) -> Option<UnaryFeature> {
+    pub fn get_constricted_glottis(segment: &Segment) -> Option<UnaryFeature> {
         segment
             .autosegmental_features
             .laryngeal
@@ -296,7 +762,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for voice feature.
-    pub fn get_voice(segment: Segment) -> Option<BinaryFeature> {
+    pub fn get_voice(segment: &Segment) -> Option<BinaryFeature> {
         segment
             .autosegmental_features
             .laryngeal
@@ -304,12 +770,12 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for rhotic feature.
-    pub fn get_rhotic(segment: Segment) -> Option<UnaryFeature> {
+    pub fn get_rhotic(segment: &Segment) -> Option<UnaryFeature> {
         segment.autosegmental_features.rhotic
     }
 
     /// structure-blind accessor for labial feature.
-    pub fn get_labial(segment: Segment) -> Option<LabialFeature> {
+    pub fn get_labial(segment: &Segment) -> Option<LabialFeature> {
         segment
             .autosegmental_features
             .place
@@ -317,7 +783,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for round feature.
-    pub fn get_round(segment: Segment) -> Option<UnaryFeature> {
+    pub fn get_round(segment: &Segment) -> Option<UnaryFeature> {
         segment
             .autosegmental_features
             .place
@@ -326,7 +792,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for coronal feature.
-    pub fn get_coronal(segment: Segment) -> Option<CoronalFeature> {
+    pub fn get_coronal(segment: &Segment) -> Option<CoronalFeature> {
         segment
             .autosegmental_features
             .place
@@ -334,7 +800,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for anterior feature.
-    pub fn get_anterior(segment: Segment) -> Option<BinaryFeature> {
+    pub fn get_anterior(segment: &Segment) -> Option<BinaryFeature> {
         segment
             .autosegmental_features
             .place
@@ -343,7 +809,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for distrib feature.
-    pub fn get_distrib(segment: Segment) -> Option<BinaryFeature> {
+    pub fn get_distrib(segment: &Segment) -> Option<BinaryFeature> {
         segment
             .autosegmental_features
             .place
@@ -352,7 +818,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for dorsal feature.
-    pub fn get_dorsal(segment: Segment) -> Option<DorsalFeature> {
+    pub fn get_dorsal(segment: &Segment) -> Option<DorsalFeature> {
         segment
             .autosegmental_features
             .place
@@ -360,7 +826,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for high feature.
-    pub fn get_high(segment: Segment) -> Option<BinaryFeature> {
+    pub fn get_high(segment: &Segment) -> Option<BinaryFeature> {
         segment
             .autosegmental_features
             .place
@@ -369,7 +835,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for low feature.
-    pub fn get_low(segment: Segment) -> Option<BinaryFeature> {
+    pub fn get_low(segment: &Segment) -> Option<BinaryFeature> {
         segment
             .autosegmental_features
             .place
@@ -378,7 +844,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for back feature.
-    pub fn get_back(segment: Segment) -> Option<BinaryFeature> {
+    pub fn get_back(segment: &Segment) -> Option<BinaryFeature> {
         segment
             .autosegmental_features
             .place
@@ -387,7 +853,7 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for pharyngeal feature.
-    pub fn get_pharyngeal(segment: Segment) -> Option<PharyngealFeature> {
+    pub fn get_pharyngeal(segment: &Segment) -> Option<PharyngealFeature> {
         segment
             .autosegmental_features
             .place
@@ -395,7 +861,9 @@ pub mod accessors {
     }
 
     /// structure-blind accessor for advanced_tongue_root feature.
-    pub fn get_advanced_tongue_root(segment: Segment) -> Option<BinaryFeature> {
+    pub fn get_advanced_tongue_root(
+        segment: &Segment,
+    ) -> Option<BinaryFeature> {
         segment
             .autosegmental_features
             .place
@@ -447,49 +915,49 @@ pub mod accessors {
 
         #[test]
         fn test_accessor_consonantal() {
-            let feature = get_consonantal(TEST_SEGMENT);
+            let feature = get_consonantal(&TEST_SEGMENT);
             assert_eq!(feature, BinaryFeature::Marked)
         }
 
         #[test]
         fn test_accessor_sonorant() {
-            let feature = get_sonorant(TEST_SEGMENT);
+            let feature = get_sonorant(&TEST_SEGMENT);
             assert_eq!(feature, BinaryFeature::Marked)
         }
 
         #[test]
         fn test_accessor_syllabic() {
-            let feature = get_syllabic(TEST_SEGMENT);
+            let feature = get_syllabic(&TEST_SEGMENT);
             assert_eq!(feature, BinaryFeature::Marked)
         }
 
         #[test]
         fn test_accessor_continuant() {
-            let feature = get_continuant(TEST_SEGMENT);
+            let feature = get_continuant(&TEST_SEGMENT);
             assert_eq!(feature, Some(BinaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_strident() {
-            let feature = get_strident(TEST_SEGMENT);
+            let feature = get_strident(&TEST_SEGMENT);
             assert_eq!(feature, Some(BinaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_lateral() {
-            let feature = get_lateral(TEST_SEGMENT);
+            let feature = get_lateral(&TEST_SEGMENT);
             assert_eq!(feature, Some(UnaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_nasal() {
-            let feature = get_nasal(TEST_SEGMENT);
+            let feature = get_nasal(&TEST_SEGMENT);
             assert_eq!(feature, Some(UnaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_laryngeal() {
-            let feature = get_laryngeal(TEST_SEGMENT);
+            let feature = get_laryngeal(&TEST_SEGMENT);
             assert_eq!(
                 feature,
                 Some(LaryngealFeatures {
@@ -502,31 +970,31 @@ pub mod accessors {
 
         #[test]
         fn test_accessor_spread_glottis() {
-            let feature = get_spread_glottis(TEST_SEGMENT);
+            let feature = get_spread_glottis(&TEST_SEGMENT);
             assert_eq!(feature, Some(UnaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_constricted_glottis() {
-            let feature = get_constricted_glottis(TEST_SEGMENT);
+            let feature = get_constricted_glottis(&TEST_SEGMENT);
             assert_eq!(feature, Some(UnaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_voice() {
-            let feature = get_voice(TEST_SEGMENT);
+            let feature = get_voice(&TEST_SEGMENT);
             assert_eq!(feature, Some(BinaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_rhotic() {
-            let feature = get_rhotic(TEST_SEGMENT);
+            let feature = get_rhotic(&TEST_SEGMENT);
             assert_eq!(feature, Some(UnaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_labial() {
-            let feature = get_labial(TEST_SEGMENT);
+            let feature = get_labial(&TEST_SEGMENT);
             assert_eq!(
                 feature,
                 Some(LabialFeature {
@@ -537,13 +1005,13 @@ pub mod accessors {
 
         #[test]
         fn test_accessor_round() {
-            let feature = get_round(TEST_SEGMENT);
+            let feature = get_round(&TEST_SEGMENT);
             assert_eq!(feature, Some(UnaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_coronal() {
-            let feature = get_coronal(TEST_SEGMENT);
+            let feature = get_coronal(&TEST_SEGMENT);
             assert_eq!(
                 feature,
                 Some(CoronalFeature {
@@ -555,19 +1023,19 @@ pub mod accessors {
 
         #[test]
         fn test_accessor_anterior() {
-            let feature = get_anterior(TEST_SEGMENT);
+            let feature = get_anterior(&TEST_SEGMENT);
             assert_eq!(feature, Some(BinaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_distrib() {
-            let feature = get_distrib(TEST_SEGMENT);
+            let feature = get_distrib(&TEST_SEGMENT);
             assert_eq!(feature, Some(BinaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_dorsal() {
-            let feature = get_dorsal(TEST_SEGMENT);
+            let feature = get_dorsal(&TEST_SEGMENT);
             assert_eq!(
                 feature,
                 Some(DorsalFeature {
@@ -580,25 +1048,25 @@ pub mod accessors {
 
         #[test]
         fn test_accessor_high() {
-            let feature = get_high(TEST_SEGMENT);
+            let feature = get_high(&TEST_SEGMENT);
             assert_eq!(feature, Some(BinaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_low() {
-            let feature = get_low(TEST_SEGMENT);
+            let feature = get_low(&TEST_SEGMENT);
             assert_eq!(feature, Some(BinaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_back() {
-            let feature = get_back(TEST_SEGMENT);
+            let feature = get_back(&TEST_SEGMENT);
             assert_eq!(feature, Some(BinaryFeature::Marked))
         }
 
         #[test]
         fn test_accessor_pharyngeal() {
-            let feature = get_pharyngeal(TEST_SEGMENT);
+            let feature = get_pharyngeal(&TEST_SEGMENT);
             assert_eq!(
                 feature,
                 Some(PharyngealFeature {
@@ -609,8 +1077,247 @@ pub mod accessors {
 
         #[test]
         fn test_accessor_advanced_tongue_root() {
-            let feature = get_advanced_tongue_root(TEST_SEGMENT);
+            let feature = get_advanced_tongue_root(&TEST_SEGMENT);
             assert_eq!(feature, Some(BinaryFeature::Marked))
         }
     }
 }
+
+/// composable segment-level transformations
+///
+/// Each transformation takes a segment and returns a new, transformed
+/// segment, leaving the original untouched.
+pub mod transform {
+    use super::*;
+
+    /// round a segment, marking the round feature. A labial node is inserted
+    /// if the segment did not already have one.
+    pub fn round(seg: Segment) -> Segment {
+        let mut seg = seg;
+        seg.autosegmental_features
+            .place
+            .get_or_insert(Place::default())
+            .labial
+            .get_or_insert(LabialFeature::default())
+            .round = Some(UnaryFeature::Marked);
+        seg
+    }
+
+    /// unround a segment, clearing the round feature. Any labial node on the
+    /// segment is kept, so a bilabial consonant remains labial.
+    pub fn unround(seg: Segment) -> Segment {
+        let mut seg = seg;
+        if let Some(labial) = seg
+            .autosegmental_features
+            .place
+            .as_mut()
+            .and_then(|place| place.labial.as_mut())
+        {
+            labial.round = None;
+        }
+        seg
+    }
+
+    /// neutralize_place removes the place node from a segment, as in
+    /// positional neutralization of place contrasts in codas. Root,
+    /// laryngeal, and other autosegmental features are kept.
+    pub fn neutralize_place(seg: Segment) -> Segment {
+        let mut seg = seg;
+        seg.autosegmental_features.place = None;
+        seg
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::builders::consonants::{alveolar, stop, vd};
+        use crate::builders::vowels::*;
+        use crate::builders::SegmentBuilder;
+
+        #[test]
+        fn test_round() {
+            let i = SegmentBuilder::vowel(&[high, front, tense], 'i');
+            let rounded_i = round(i);
+            assert_eq!(
+                rounded_i.autosegmental_features.place.unwrap().labial,
+                Some(LabialFeature {
+                    round: Some(UnaryFeature::Marked)
+                })
+            );
+        }
+
+        #[test]
+        fn test_unround() {
+            let u = SegmentBuilder::vowel(&[high, back, rounded, tense], 'u');
+            let unrounded_u = unround(u);
+            assert_eq!(
+                unrounded_u.autosegmental_features.place.unwrap().labial,
+                Some(LabialFeature { round: None })
+            );
+        }
+
+        #[test]
+        fn test_unround_non_labial() {
+            let i = SegmentBuilder::vowel(&[high, front, tense], 'i');
+            assert_eq!(unround(i), i);
+        }
+
+        #[test]
+        fn test_neutralize_place() {
+            let d = SegmentBuilder::consonant(&[vd, alveolar, stop], 'd');
+            let neutral_d = neutralize_place(d);
+            assert_eq!(neutral_d.autosegmental_features.place, None);
+            assert_eq!(
+                neutral_d.autosegmental_features.laryngeal,
+                d.autosegmental_features.laryngeal
+            );
+            assert_eq!(
+                neutral_d.autosegmental_features.continuant,
+                Some(BinaryFeature::Unmarked)
+            );
+            assert_eq!(neutral_d.root_features, d.root_features);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builders::consonants::*;
+    use crate::builders::SegmentBuilder;
+
+    #[test]
+    fn test_pretty() {
+        let p = SegmentBuilder::consonant(&[vl, bilabial, stop], 'p');
+        let tree = pretty(&p);
+        assert!(tree.contains("labial"));
+        assert!(tree.contains("-voice"));
+        assert!(!tree.contains("dorsal"));
+        assert_eq!(
+            tree,
+            "p\n  +consonantal\n  -sonorant\n  -syllabic\n  place\n    labial\n  \
+             -continuant\n  laryngeal\n    -voice"
+        );
+    }
+
+    #[test]
+    fn test_feature_key() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::collections::HashSet;
+
+        let script_g = SegmentBuilder::consonant(&[vd, velar, stop], 'ɡ');
+        let latin_g = SegmentBuilder::consonant(&[vd, velar, stop], 'g');
+        let k = SegmentBuilder::consonant(&[vl, velar, stop], 'k');
+        assert_ne!(script_g, latin_g);
+        assert_eq!(script_g.feature_key(), latin_g.feature_key());
+        assert_ne!(script_g.feature_key(), k.feature_key());
+
+        let hash = |key: FeatureKey| {
+            let mut hasher = DefaultHasher::new();
+            key.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(script_g.feature_key()), hash(latin_g.feature_key()));
+
+        let keys: HashSet<FeatureKey> = vec![script_g, latin_g, k]
+            .into_iter()
+            .map(Segment::feature_key)
+            .collect();
+        assert_eq!(keys.len(), 2);
+    }
+
+    #[test]
+    fn test_specified_autosegmental_count() {
+        use crate::builders::vowels::{front, high, tense};
+
+        let template = SegmentBuilder::segment(&[], 'X');
+        assert_eq!(specified_autosegmental_count(&template), 0);
+
+        // place, dorsal (high, low, back), pharyngeal (ATR)
+        let i = SegmentBuilder::vowel(&[high, front, tense], 'i');
+        assert_eq!(specified_autosegmental_count(&i), 7);
+
+        let any_nasal = SegmentBuilder::consonant(&[nasal], 'N');
+        assert!(
+            specified_autosegmental_count(&any_nasal)
+                < specified_autosegmental_count(&i)
+        );
+    }
+
+    #[test]
+    fn test_matches_underspecified() {
+        let any_nasal = SegmentBuilder::consonant(&[nasal], 'N');
+        let m = SegmentBuilder::consonant(&[vd, bilabial, nasal], 'm');
+        let n = SegmentBuilder::consonant(&[vd, alveolar, nasal], 'n');
+        assert!(any_nasal.matches(&m));
+        assert!(any_nasal.matches(&n));
+        assert!(!m.matches(&any_nasal));
+    }
+
+    #[test]
+    fn test_matches_conflicting() {
+        let p = SegmentBuilder::consonant(&[vl, bilabial, stop], 'p');
+        let b = SegmentBuilder::consonant(&[vd, bilabial, stop], 'b');
+        let voiceless = SegmentBuilder::consonant(&[vl], 'V');
+        assert!(voiceless.matches(&p));
+        assert!(!voiceless.matches(&b));
+        assert!(!p.matches(&b));
+    }
+
+    #[test]
+    fn test_matches_self_ignores_symbol() {
+        let p = SegmentBuilder::consonant(&[vl, bilabial, stop], 'p');
+        let x = SegmentBuilder::consonant(&[vl, bilabial, stop], 'x');
+        assert!(p.matches(&x));
+    }
+
+    #[test]
+    fn test_unify_spreads_nasal() {
+        let vowel = SegmentBuilder::vowel(&[], 'a');
+        let mut nasal_spec = SegmentBuilder::vowel(&[], 'N');
+        nasal_spec.autosegmental_features.nasal = Some(UnaryFeature::Marked);
+        let unified = vowel.unify(&nasal_spec).unwrap();
+        assert_eq!(
+            unified.autosegmental_features.nasal,
+            Some(UnaryFeature::Marked)
+        );
+        assert_eq!(unified.symbol, 'a');
+    }
+
+    #[test]
+    fn test_unify_fills_place() {
+        let stop_seg = SegmentBuilder::consonant(&[vl, stop], 'T');
+        let labial_seg = SegmentBuilder::consonant(&[bilabial], 'B');
+        assert_eq!(
+            stop_seg.unify(&labial_seg),
+            Ok(SegmentBuilder::consonant(&[vl, stop, bilabial], 'T'))
+        );
+    }
+
+    #[test]
+    fn test_unify_conflicting_voice() {
+        let p = SegmentBuilder::consonant(&[vl, bilabial, stop], 'p');
+        let b = SegmentBuilder::consonant(&[vd, bilabial, stop], 'b');
+        let err = p.unify(&b).unwrap_err();
+        assert_eq!(err.feature(), "voice");
+    }
+
+    #[test]
+    fn test_interpolate_diphthong() {
+        use crate::accents::genam::phoneme;
+        use crate::phoneme::Phoneme;
+
+        let (a, ih) = match phoneme("a͡ɪ").unwrap() {
+            Phoneme::Disegment(a, ih) => (a, ih),
+            _ => panic!("expected a disegment"),
+        };
+        let mid = interpolate(&a, &ih);
+        let dorsal = mid.autosegmental_features.place.unwrap().dorsal.unwrap();
+        assert_eq!(dorsal.high, None);
+        assert_eq!(dorsal.low, None);
+        assert_eq!(dorsal.back, Some(BinaryFeature::Unmarked));
+        assert_eq!(mid.root_features, a.root_features);
+        assert_eq!(mid.symbol, 'a');
+        assert!(interpolate(&a, &a).feature_eq(&a));
+    }
+}
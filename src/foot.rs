@@ -0,0 +1,105 @@
+//! Metrical feet
+//!
+//! A foot is the unit of meter in verse: a short group of syllables with a
+//! fixed pattern of stressed and unstressed positions. Feet here are defined
+//! over binary stress, since scansion only distinguishes stressed from
+//! unstressed syllables.
+
+use crate::stress::BinaryStress;
+use crate::stress::BinaryStress::{Stressed, Unstressed};
+
+/// Foot is a named pattern of stressed and unstressed syllables.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum Foot {
+    /// unstressed, stressed (u S): "a-bove"
+    Iamb,
+    /// stressed, unstressed (S u): "pump-kin"
+    Trochee,
+    /// stressed, unstressed, unstressed (S u u): "mer-ri-ly"
+    Dactyl,
+    /// unstressed, unstressed, stressed (u u S): "in-ter-vene"
+    Anapest,
+    /// stressed, stressed (S S)
+    Spondee,
+    /// unstressed, unstressed (u u)
+    Pyrrhic,
+}
+
+impl Foot {
+    /// pattern provides the sequence of binary stresses that make up a foot.
+    pub fn pattern(self) -> &'static [BinaryStress] {
+        match self {
+            Foot::Iamb => &[Unstressed, Stressed],
+            Foot::Trochee => &[Stressed, Unstressed],
+            Foot::Dactyl => &[Stressed, Unstressed, Unstressed],
+            Foot::Anapest => &[Unstressed, Unstressed, Stressed],
+            Foot::Spondee => &[Stressed, Stressed],
+            Foot::Pyrrhic => &[Unstressed, Unstressed],
+        }
+    }
+
+    /// from_pattern provides the foot with exactly the given sequence of
+    /// binary stresses, if there is one.
+    pub fn from_pattern(pattern: &[BinaryStress]) -> Option<Foot> {
+        FEET.iter().copied().find(|foot| foot.pattern() == pattern)
+    }
+}
+
+// Three-syllable feet are listed first so that a greedy grouping prefers them.
+const FEET: [Foot; 6] = [
+    Foot::Dactyl,
+    Foot::Anapest,
+    Foot::Iamb,
+    Foot::Trochee,
+    Foot::Spondee,
+    Foot::Pyrrhic,
+];
+
+/// scan groups a sequence of binary stresses into feet, greedily from left to
+/// right.
+///
+/// At each position, a three-syllable foot (dactyl, anapest) is taken if one
+/// matches, otherwise a two-syllable foot. Any syllables that cannot be
+/// grouped into a foot are returned as the remainder.
+pub fn scan(stresses: &[BinaryStress]) -> (Vec<Foot>, Vec<BinaryStress>) {
+    let mut feet = Vec::new();
+    let mut rest = stresses;
+    while let Some(foot) = FEET.iter().copied().find(|foot| {
+        rest.len() >= foot.pattern().len()
+            && foot.pattern() == &rest[..foot.pattern().len()]
+    }) {
+        feet.push(foot);
+        rest = &rest[foot.pattern().len()..];
+    }
+    (feet, rest.to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_pattern() {
+        assert_eq!(
+            Foot::from_pattern(&[Stressed, Unstressed]),
+            Some(Foot::Trochee)
+        );
+        assert_eq!(Foot::from_pattern(&[Stressed]), None);
+    }
+
+    #[test]
+    fn test_scan() {
+        assert_eq!(
+            scan(&[Unstressed, Stressed, Unstressed, Stressed]),
+            (vec![Foot::Iamb, Foot::Iamb], vec![])
+        );
+        assert_eq!(
+            scan(&[Stressed, Unstressed, Unstressed, Stressed, Unstressed]),
+            (vec![Foot::Dactyl, Foot::Trochee], vec![])
+        );
+        assert_eq!(
+            scan(&[Stressed, Unstressed, Stressed]),
+            (vec![Foot::Trochee], vec![Stressed])
+        );
+    }
+}
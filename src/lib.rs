@@ -6,9 +6,17 @@
 
 pub mod accents;
 pub mod builders;
+pub mod describe;
+pub mod distance;
 pub mod feature_classes;
 pub mod features;
+pub mod foot;
 pub mod phoneme;
+pub mod prelude;
+pub mod rhyme;
+pub mod rules;
 pub mod stress;
 pub mod syllable;
+pub mod timing;
+pub mod tone;
 pub mod word;
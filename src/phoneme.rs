@@ -5,7 +5,14 @@
 //! and vary from accent to accent. This package assumes that the symbolic
 //! representation for a phoneme is the International Phonetic Alphabet (IPA).
 
-use crate::features::Segment;
+use crate::accents::genam;
+use crate::builders::{consonants, rhotic, vowels, Builder, SegmentBuilder};
+use crate::feature_classes::*;
+use crate::features::accessors::*;
+use crate::features::{BinaryFeature, Segment};
+use std::cmp::Ordering;
+use std::error::Error;
+use std::fmt;
 
 ///A Phoneme is a unit of speech sound.
 ///
@@ -19,10 +26,30 @@ pub enum Phoneme {
     ///A phoneme with a single phonological segment
     Monosegment(Segment),
     ///A phoneme comprised of an ordered sequence of two segments
+    ///
+    ///Prefer [Phoneme::disegment], which rejects degenerate disegments.
     Disegment(Segment, Segment),
 }
 
 impl Phoneme {
+    ///Construct a disegment from two segments, checking that they are two
+    ///different sounds.
+    ///
+    ///A disegment of two feature_eq segments is a single sound written twice,
+    ///and should be a monosegment instead, so it is rejected with a
+    ///[PhonemeError]. Other pairs are accepted: a disegment is usually an
+    ///affricate or a diphthong (see [coalesce] for those shapes), but the
+    ///shape of the pair is left to the caller.
+    pub fn disegment(a: Segment, b: Segment) -> Result<Phoneme, PhonemeError> {
+        if a.feature_eq(&b) {
+            return Err(PhonemeError::new(&format!(
+                "DegenerateDisegment: {} and {} are the same segment",
+                a.symbol, b.symbol
+            )));
+        }
+        Ok(Phoneme::Disegment(a, b))
+    }
+
     ///The symbol associated with a phoneme. ex: 'p', 't͡ʃ'
     pub fn symbol(&self) -> String {
         match self {
@@ -32,6 +59,74 @@ impl Phoneme {
             }
         }
     }
+
+    ///Test whether two phonemes have the same segment structure and
+    ///features, ignoring symbols. See [Segment::feature_eq].
+    pub fn feature_eq(&self, other: &Phoneme) -> bool {
+        match (self, other) {
+            (Phoneme::Monosegment(a), Phoneme::Monosegment(b)) => {
+                a.feature_eq(b)
+            }
+            (Phoneme::Disegment(a1, a2), Phoneme::Disegment(b1, b2)) => {
+                a1.feature_eq(b1) && a2.feature_eq(b2)
+            }
+            _ => false,
+        }
+    }
+
+    ///The number of segments in a phoneme: 1 for a monosegment, 2 for a
+    ///disegment.
+    pub fn segment_count(&self) -> usize {
+        match self {
+            Phoneme::Monosegment(_) => 1,
+            Phoneme::Disegment(..) => 2,
+        }
+    }
+
+    ///Whether a phoneme has a single segment.
+    pub fn is_monosegment(&self) -> bool {
+        matches!(self, Phoneme::Monosegment(_))
+    }
+
+    ///Whether a phoneme is a sequence of two segments.
+    pub fn is_disegment(&self) -> bool {
+        matches!(self, Phoneme::Disegment(..))
+    }
+
+    ///The segments of a phoneme, in order. A monosegment has one segment and
+    ///a disegment has two.
+    pub fn segments(&self) -> Vec<Segment> {
+        match *self {
+            Phoneme::Monosegment(s) => vec![s],
+            Phoneme::Disegment(s1, s2) => vec![s1, s2],
+        }
+    }
+
+    ///Split a phoneme into monosegment phonemes, one for each of its segments.
+    ///A monosegment splits into itself, and a disegment into its two parts.
+    ///See [coalesce] for the inverse.
+    pub fn split(self) -> Vec<Phoneme> {
+        self.segments()
+            .into_iter()
+            .map(Phoneme::Monosegment)
+            .collect()
+    }
+
+    ///The first segment of a phoneme. For a monosegment, this is its only
+    ///segment.
+    pub fn first_segment(&self) -> Segment {
+        match *self {
+            Phoneme::Monosegment(s) | Phoneme::Disegment(s, _) => s,
+        }
+    }
+
+    ///The last segment of a phoneme. For a monosegment, this is its only
+    ///segment.
+    pub fn last_segment(&self) -> Segment {
+        match *self {
+            Phoneme::Monosegment(s) | Phoneme::Disegment(_, s) => s,
+        }
+    }
 }
 
 impl From<Segment> for Phoneme {
@@ -40,6 +135,316 @@ impl From<Segment> for Phoneme {
     }
 }
 
+///An error created when segments do not form a well-formed phoneme.
+#[derive(Debug)]
+pub struct PhonemeError {
+    msg: String,
+}
+
+impl PhonemeError {
+    fn new(msg: &str) -> PhonemeError {
+        PhonemeError {
+            msg: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for PhonemeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl Error for PhonemeError {}
+
+///The best symbol for rendering a phoneme: its GenAm symbol if it has the
+///features of a GenAm phoneme (see
+///[symbol_for](crate::accents::genam::symbol_for)), and otherwise its own
+///stored symbol.
+///
+///This gives the correct glyph for a phoneme built from features with a
+///placeholder symbol.
+pub fn best_symbol(p: &Phoneme) -> String {
+    genam::symbol_for(p)
+        .map(String::from)
+        .unwrap_or_else(|| p.symbol())
+}
+
+///Build a phoneme from a prose articulatory description, such as "voiceless
+///bilabial stop" or "high front tense vowel". This is the inverse of
+///[describe](crate::describe::describe).
+///
+///Consonants are described by voicing, place, and manner, in that order:
+///- voicing: voiced, voiceless
+///- place: bilabial, labiodental, dental, alveolar, postalveolar, palatal,
+///  velar, labial-velar, glottal
+///- manner: stop, nasal, fricative, affricate, glide, approximant, lateral
+///  approximant
+///
+///Vowels are described by a height (high, mid, low) and any of: a backness
+///(front, central, back), a tenseness (tense, lax), a rounding (rounded or
+///round, unrounded), nasalized, r-colored, and a trailing "vowel". A diphthong
+///joins the descriptions of its two parts with "to", as describe does.
+///
+///Returns None if the description has any unknown or repeated words, is
+///missing a part, or describes a diphthong whose two parts are the same. The
+///built phoneme has the placeholder symbol '?'; use [best_symbol] to render
+///it.
+pub fn from_desc(desc: &str) -> Option<Phoneme> {
+    if let Some((first, second)) = desc.split_once(" to ") {
+        return Phoneme::disegment(
+            vowel_from_desc(first)?,
+            vowel_from_desc(second)?,
+        )
+        .ok();
+    }
+    let words: Vec<&str> = desc.split_whitespace().collect();
+    if words.iter().any(|w| ["high", "mid", "low"].contains(w)) {
+        vowel_from_desc(desc).map(Phoneme::Monosegment)
+    } else {
+        consonant_from_desc(&words)
+    }
+}
+
+const PLACEHOLDER: char = '?';
+
+fn consonant_from_desc(words: &[&str]) -> Option<Phoneme> {
+    let (voicing, place_word, manner) = match words {
+        [voicing, place, manner @ ..] => (*voicing, *place, manner),
+        _ => return None,
+    };
+    let voicing: Builder = match voicing {
+        "voiced" => consonants::vd,
+        "voiceless" => consonants::vl,
+        _ => return None,
+    };
+    let place: &[Builder] = match place_word {
+        "bilabial" => &[consonants::bilabial],
+        "labiodental" => &[consonants::labiodental],
+        "dental" => &[consonants::dental],
+        "alveolar" => &[consonants::alveolar],
+        "postalveolar" => &[consonants::postalveolar],
+        "palatal" => &[consonants::palatal],
+        "velar" => &[consonants::velar],
+        "labial-velar" => &[consonants::bilabial, consonants::velar],
+        "glottal" => &[consonants::glottal],
+        _ => return None,
+    };
+    let manner: Builder = match manner {
+        ["stop"] if place_word == "glottal" => consonants::glottal_stop,
+        ["stop"] => consonants::stop,
+        ["nasal"] => consonants::nasal,
+        ["fricative"] => consonants::fricative,
+        ["glide"] => consonants::glide,
+        ["approximant"] => consonants::approximant,
+        ["lateral", "approximant"] => |s| {
+            consonants::lateral(s);
+            consonants::approximant(s);
+        },
+        ["affricate"] => {
+            // the stop closure of a postalveolar affricate is alveolar, as in
+            // 't͡ʃ'
+            let closure: &[Builder] = if place_word == "postalveolar" {
+                &[consonants::alveolar]
+            } else {
+                place
+            };
+            let stop = [&[voicing], closure, &[consonants::stop]].concat();
+            let release =
+                [&[voicing], place, &[consonants::fricative]].concat();
+            return Some(Phoneme::Disegment(
+                SegmentBuilder::consonant(&stop, PLACEHOLDER),
+                SegmentBuilder::consonant(&release, PLACEHOLDER),
+            ));
+        }
+        _ => return None,
+    };
+    let builders = [&[voicing], place, &[manner]].concat();
+    Some(Phoneme::Monosegment(SegmentBuilder::consonant(
+        &builders,
+        PLACEHOLDER,
+    )))
+}
+
+fn vowel_from_desc(desc: &str) -> Option<Segment> {
+    let mut height = None;
+    let mut tenseness = None;
+    let mut backness = None;
+    let mut rounding = None;
+    let mut nasality = None;
+    let mut rhoticity = None;
+    for word in desc.split_whitespace() {
+        let slot = match word {
+            "high" | "mid" | "low" => &mut height,
+            "tense" | "lax" => &mut tenseness,
+            "front" | "central" | "back" => &mut backness,
+            "rounded" | "round" | "unrounded" => &mut rounding,
+            "nasalized" => &mut nasality,
+            "r-colored" => &mut rhoticity,
+            "vowel" => continue,
+            _ => return None,
+        };
+        if slot.replace(word).is_some() {
+            return None;
+        }
+    }
+    let height: Builder = match (height?, tenseness) {
+        ("high", Some("lax")) => vowels::near_high,
+        ("mid", Some("lax")) => vowels::open_mid,
+        ("low", Some("lax")) => vowels::near_low,
+        ("high", _) => vowels::high,
+        ("mid", _) => vowels::mid,
+        _ => vowels::low,
+    };
+    let mut builders = vec![height];
+    match backness {
+        Some("front") => builders.push(vowels::front),
+        Some("central") => builders.push(vowels::central),
+        Some(_) => builders.push(vowels::back),
+        None => (),
+    }
+    if rounding.is_some_and(|r| r != "unrounded") {
+        builders.push(vowels::rounded);
+    }
+    if tenseness == Some("tense") {
+        builders.push(vowels::tense);
+    }
+    if nasality.is_some() {
+        builders.push(vowels::nasalized);
+    }
+    if rhoticity.is_some() {
+        builders.push(rhotic);
+    }
+    Some(SegmentBuilder::vowel(&builders, PLACEHOLDER))
+}
+
+///Compare two phonemes for a canonical ordering, such as for sorting an
+///inventory reproducibly with `sort_by(phoneme::cmp)`.
+///
+///Phonemes are ordered first by sonority, from least to most sonorous:
+///stops, affricates, fricatives, nasals, liquids, glides, vowels. This is the
+///sonority hierarchy that governs the order of sounds within a syllable, so
+///an inventory reads from the edges of a syllable toward its nucleus.
+///
+///Consonants of the same sonority are then ordered by place, from the front
+///of the mouth to the back: labial, coronal, dorsal, pharyngeal, and no oral
+///place (glottal). A segment with more than one place (as in 'w') is ordered
+///by its frontmost place. Syllabic consonants ('m̩') are ordered as the
+///consonants they are built from. Vowels are ordered by height (high, mid,
+///low), then by backness (front, then back). After sonority and place,
+///voiceless phonemes come before voiced, monosegments before disegments, and
+///any remaining ties are broken by symbol, so that the order is total.
+///
+///This ordering is not consistent with ==: two phonemes with different
+///features that agree on every key above compare as Equal, which is why
+///Phoneme does not implement Ord.
+pub fn cmp(a: &Phoneme, b: &Phoneme) -> Ordering {
+    let key = |p: &Phoneme| {
+        (
+            sonority_rank(*p),
+            place_rank(p.first_segment()),
+            is_voiced(*p),
+            p.segment_count(),
+            p.symbol(),
+        )
+    };
+    key(a).cmp(&key(b))
+}
+
+fn sonority_rank(p: Phoneme) -> u8 {
+    if is_vowel(p) {
+        6
+    } else if is_affricate(p) {
+        1
+    } else if is_stop(p) {
+        0
+    } else if is_fricative(p) {
+        2
+    } else if is_nasal(p) {
+        3
+    } else if is_glide(p) {
+        5
+    } else {
+        4
+    }
+}
+
+fn place_rank(seg: Segment) -> (u8, u8) {
+    if is_vowel(seg.into()) {
+        let height = match (get_high(&seg), get_low(&seg)) {
+            (Some(BinaryFeature::Marked), _) => 0,
+            (_, Some(BinaryFeature::Marked)) => 2,
+            _ => 1,
+        };
+        let backness = match get_back(&seg) {
+            Some(BinaryFeature::Unmarked) => 0,
+            _ => 1,
+        };
+        return (height, backness);
+    }
+    let articulator = if get_labial(&seg).is_some() {
+        0
+    } else if get_coronal(&seg).is_some() {
+        1
+    } else if get_dorsal(&seg).is_some() {
+        2
+    } else if get_pharyngeal(&seg).is_some() {
+        3
+    } else {
+        4
+    };
+    (articulator, 0)
+}
+
+///Merge adjacent monosegments into disegments where the pair has the shape
+///of an affricate or a diphthong.
+///
+///Only two patterns are recognized, and any other sequence is left as is:
+///- affricate: a stop followed by a fricative with the same voicing and the
+///  same articulator (labial, coronal, dorsal), as in "t", "ʃ" -> "t͡ʃ".
+///  Sequences like "k", "s" are not merged.
+///- diphthong: a non-high vowel followed by a high vowel, as in "a", "ɪ" ->
+///  "a͡ɪ". Vowels in hiatus of other shapes ("i", "a") are not merged.
+///
+///Pairs are merged left to right, and a segment is merged at most once.
+pub fn coalesce(phonemes: &[Phoneme]) -> Vec<Phoneme> {
+    let mut merged = Vec::new();
+    let mut rest = phonemes.iter().copied().peekable();
+    while let Some(p) = rest.next() {
+        match (p, rest.peek().copied()) {
+            (Phoneme::Monosegment(a), Some(Phoneme::Monosegment(b)))
+                if is_affricate_shape(a, b) || is_diphthong_shape(a, b) =>
+            {
+                rest.next();
+                merged.push(Phoneme::Disegment(a, b));
+            }
+            _ => merged.push(p),
+        }
+    }
+    merged
+}
+
+fn is_affricate_shape(a: Segment, b: Segment) -> bool {
+    let articulators = |s: Segment| {
+        s.autosegmental_features.place.map(|place| {
+            (
+                place.labial.is_some(),
+                place.coronal.is_some(),
+                place.dorsal.is_some(),
+            )
+        })
+    };
+    is_stop(a.into())
+        && is_fricative(b.into())
+        && is_voiced(a.into()) == is_voiced(b.into())
+        && articulators(a).is_some()
+        && articulators(a) == articulators(b)
+}
+
+fn is_diphthong_shape(a: Segment, b: Segment) -> bool {
+    is_vowel(a.into()) && !is_high_vowel(a.into()) && is_high_vowel(b.into())
+}
+
 #[cfg(test)]
 mod tests {
     use super::Phoneme::{Disegment, Monosegment};
@@ -59,4 +464,195 @@ mod tests {
         );
         assert_eq!(m.symbol(), "t͡ʃ")
     }
+
+    #[test]
+    fn test_disegment() {
+        use super::Phoneme;
+        use crate::accents::genam::phoneme;
+
+        let (t, sh) = match phoneme("t͡ʃ").unwrap() {
+            Disegment(t, sh) => (t, sh),
+            _ => panic!("expected a disegment"),
+        };
+        assert_eq!(Phoneme::disegment(t, sh).unwrap(), Disegment(t, sh));
+        let err = Phoneme::disegment(t, t).unwrap_err();
+        assert!(err.to_string().starts_with("DegenerateDisegment"));
+        let mut other_t = t;
+        other_t.symbol = 'T';
+        assert!(Phoneme::disegment(t, other_t).is_err());
+    }
+
+    #[test]
+    fn test_feature_eq() {
+        use crate::accents::genam::phoneme;
+        use crate::builders::consonants::*;
+
+        let script_g = phoneme("ɡ").unwrap();
+        let latin_g =
+            Monosegment(SegmentBuilder::consonant(&[vd, velar, stop], 'g'));
+        assert!(script_g.feature_eq(&latin_g));
+        assert_ne!(script_g, latin_g);
+        assert!(!script_g.feature_eq(&phoneme("k").unwrap()));
+        assert!(!phoneme("t͡ʃ").unwrap().feature_eq(&phoneme("t").unwrap()));
+    }
+
+    #[test]
+    fn test_from_desc() {
+        use super::from_desc;
+        use crate::accents::genam::phoneme;
+
+        let phon = |s| phoneme(s).unwrap();
+        let built = |d| from_desc(d).unwrap();
+        assert!(built("voiceless bilabial stop").feature_eq(&phon("p")));
+        assert!(built("voiced velar nasal").feature_eq(&phon("ŋ")));
+        assert!(built("voiced labial-velar glide").feature_eq(&phon("w")));
+        assert!(built("voiceless glottal stop").feature_eq(&phon("ʔ")));
+        assert!(built("high front tense vowel").feature_eq(&phon("i")));
+        assert!(built("high back round tense").feature_eq(&phon("u")));
+        assert!(built("mid front lax").feature_eq(&phon("ɛ")));
+        assert!(built("low back vowel").feature_eq(&phon("ɑ")));
+        assert_eq!(built("voiced bilabial stop").symbol(), "?");
+        assert!(built("voiceless postalveolar affricate").is_disegment());
+    }
+
+    #[test]
+    fn test_from_desc_unknown() {
+        use super::from_desc;
+
+        assert_eq!(from_desc("voiceless bilabial click"), None);
+        assert_eq!(from_desc("bilabial stop"), None);
+        assert_eq!(from_desc("voiced uvular stop"), None);
+        assert_eq!(from_desc("high low front vowel"), None);
+        assert_eq!(from_desc("high front sharp vowel"), None);
+        assert_eq!(from_desc("high front vowel to"), None);
+        assert_eq!(from_desc("low back vowel to low back vowel"), None);
+        assert_eq!(from_desc(""), None);
+    }
+
+    #[test]
+    fn test_from_desc_roundtrip() {
+        use super::from_desc;
+        use crate::accents::genam;
+        use crate::describe::describe;
+
+        for (sym, p) in genam::entries() {
+            let desc = describe(&p);
+            let built = from_desc(&desc).unwrap_or_else(|| panic!("{}", sym));
+            assert_eq!(describe(&built), desc, "{}", sym);
+        }
+    }
+
+    #[test]
+    fn test_best_symbol() {
+        use super::best_symbol;
+        use crate::builders::consonants::*;
+
+        let p =
+            Monosegment(SegmentBuilder::consonant(&[vl, bilabial, stop], '?'));
+        assert_eq!(p.symbol(), "?");
+        assert_eq!(best_symbol(&p), "p");
+        let x = Monosegment(SegmentBuilder::consonant(
+            &[vl, velar, fricative],
+            'x',
+        ));
+        assert_eq!(best_symbol(&x), "x");
+    }
+
+    #[test]
+    fn test_cmp() {
+        use super::Phoneme;
+        use crate::accents::genam::phoneme;
+        use std::cmp::Ordering;
+
+        let phon = |s| phoneme(s).unwrap();
+        let mut inventory: Vec<Phoneme> =
+            ["ɑ", "w", "l", "n", "s", "t͡ʃ", "k", "b", "h", "p", "i", "t"]
+                .iter()
+                .map(|s| phon(s))
+                .collect();
+        inventory.sort_by(super::cmp);
+        let symbols: Vec<String> =
+            inventory.iter().map(|p| p.symbol()).collect();
+        assert_eq!(
+            symbols,
+            vec!["p", "b", "t", "k", "t͡ʃ", "s", "h", "n", "l", "w", "i", "ɑ"]
+        );
+        assert_eq!(super::cmp(&phon("p"), &phon("p")), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_cmp_syllabic_consonant() {
+        use crate::accents::genam::phoneme;
+        use crate::builders::consonants::*;
+
+        let syllabic_m = Monosegment(SegmentBuilder::consonant(
+            &[vd, bilabial, nasal, syllabic],
+            'M',
+        ));
+        let mut nasals =
+            [phoneme("n").unwrap(), syllabic_m, phoneme("m").unwrap()];
+        nasals.sort_by(super::cmp);
+        let symbols: Vec<String> = nasals.iter().map(|p| p.symbol()).collect();
+        assert_eq!(symbols, vec!["M", "m", "n"]);
+    }
+
+    #[test]
+    fn test_split() {
+        use crate::accents::genam::phoneme;
+
+        let phon = |s| phoneme(s).unwrap();
+        assert_eq!(phon("t").split(), vec![phon("t")]);
+        assert_eq!(phon("t͡ʃ").split(), vec![phon("t"), phon("ʃ")]);
+        assert_eq!(super::coalesce(&phon("t͡ʃ").split()), vec![phon("t͡ʃ")]);
+    }
+
+    #[test]
+    fn test_coalesce() {
+        use super::coalesce;
+        use crate::accents::genam::phoneme;
+
+        let phon = |s| phoneme(s).unwrap();
+        assert_eq!(coalesce(&[phon("t"), phon("ʃ")]), vec![phon("t͡ʃ")]);
+        assert_eq!(
+            coalesce(&[phon("t"), phon("ʃ"), phon("ɪ"), phon("p")]),
+            vec![phon("t͡ʃ"), phon("ɪ"), phon("p")]
+        );
+        // no merging across voicing, articulators, or disegments
+        let unmerged = [phon("t"), phon("ʒ"), phon("k"), phon("s")];
+        assert_eq!(coalesce(&unmerged), unmerged.to_vec());
+        assert_eq!(
+            coalesce(&[phon("t͡ʃ"), phon("ʃ")]),
+            vec![phon("t͡ʃ"), phon("ʃ")]
+        );
+
+        let merged = coalesce(&[phon("ɑ"), phon("ɪ")]);
+        assert_eq!(merged.len(), 1);
+        assert_eq!(merged[0].symbol(), "ɑ͡ɪ");
+        assert_eq!(coalesce(&[phon("i"), phon("ɑ")]).len(), 2);
+    }
+
+    #[test]
+    fn test_segments_monosegment() {
+        let p = SegmentBuilder::segment(&[], 'p');
+        let m = Monosegment(p);
+        assert_eq!(m.segments(), vec![p]);
+        assert_eq!(m.segment_count(), 1);
+        assert!(m.is_monosegment());
+        assert!(!m.is_disegment());
+        assert_eq!(m.first_segment(), p);
+        assert_eq!(m.last_segment(), p);
+    }
+
+    #[test]
+    fn test_segments_disegment() {
+        let t = SegmentBuilder::segment(&[], 't');
+        let sh = SegmentBuilder::segment(&[], 'ʃ');
+        let m = Disegment(t, sh);
+        assert_eq!(m.segments(), vec![t, sh]);
+        assert_eq!(m.segment_count(), 2);
+        assert!(!m.is_monosegment());
+        assert!(m.is_disegment());
+        assert_eq!(m.first_segment(), t);
+        assert_eq!(m.last_segment(), sh);
+    }
 }
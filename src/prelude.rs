@@ -0,0 +1,38 @@
+//! Commonly used items, re-exported for glob import
+//!
+//! The prelude gathers the types, builders, and natural-class predicates that
+//! most uses of this crate need, so that a single import brings them in.
+//!
+//! # Examples
+//!
+//! ```
+//! use sound::prelude::*;
+//!
+//! let p = Phoneme::Monosegment(SegmentBuilder::consonant(&[vl, bilabial, stop], 'p'));
+//! let er = Phoneme::Monosegment(SegmentBuilder::vowel(&[mid, front, rhotic], 'ɚ'));
+//!
+//! assert!(is_stop(p));
+//! assert!(is_rhotic_vowel(er));
+//! ```
+
+pub use crate::builders::consonants::{
+    alveolar, approximant, bilabial, dental, distrib, fricative, glide,
+    glottal, glottal_stop, labialized, labiodental, lateral, nasal, palatal,
+    palatalized, postalveolar, sibilant, stop, syllabic, vd, velar, velarized,
+    vl,
+};
+pub use crate::builders::vowels::{
+    back, central, front, high, low, mid, nasalized, near_high, near_low,
+    open_mid, rounded, tense, unrounded,
+};
+pub use crate::builders::{rhotic, SegmentBuilder};
+pub use crate::feature_classes::*;
+pub use crate::features::{
+    AutosegmentalFeatures, BinaryFeature, CoronalFeature, DorsalFeature,
+    LabialFeature, LaryngealFeatures, PharyngealFeature, Place, RootFeatures,
+    Segment, UnaryFeature,
+};
+pub use crate::phoneme::Phoneme;
+pub use crate::stress::Stress;
+pub use crate::syllable::Syllable;
+pub use crate::word::Word;
@@ -0,0 +1,110 @@
+//! Rhyme between words
+//!
+//! Rhyme provides tools for finding which words rhyme with one another. Two
+//! words rhyme here when the rhymes (nucleus and coda) of their final stressed
+//! syllables are made of exactly the same phonemes.
+
+use crate::stress::Stress;
+use crate::syllable::Syllable;
+use crate::word::Word;
+
+/// scheme labels a sequence of words (such as the final words of the lines of
+/// a stanza) with a rhyme scheme.
+///
+/// Each word is given a letter, starting with 'a'. Words that rhyme share a
+/// letter, and each new rhyme is given the next unused letter, so a stanza
+/// whose lines end in "cat", "hat", "dog", "log" is labelled a, a, b, b.
+/// Letters wrap back around to 'a' after the 26th distinct rhyme.
+///
+/// A word's rhyme is taken from its final stressed syllable. A syllable with
+/// no stress information (as in a monosyllable) counts as stressed, and a
+/// word with no stressed syllable falls back to its last syllable.
+pub fn scheme(words: &[Word]) -> Vec<char> {
+    let mut seen: Vec<Option<&Syllable>> = Vec::new();
+    let mut labels = Vec::new();
+
+    for word in words {
+        let syl = rhyming_syllable(word);
+        let index = seen
+            .iter()
+            .position(|other| match (other, syl) {
+                (Some(a), Some(b)) => a.rhyme() == b.rhyme(),
+                _ => false,
+            })
+            .unwrap_or_else(|| {
+                seen.push(syl);
+                seen.len() - 1
+            });
+        labels.push((b'a' + (index % 26) as u8) as char);
+    }
+
+    labels
+}
+
+/// rhyme_key provides a canonical string for the rhyme (nucleus and coda) of
+/// a syllable, built from the symbols of its phonemes.
+///
+/// Syllables that rhyme perfectly share a key, so keys can be used to group
+/// words into buckets (as in a rhyming dictionary) without comparing every
+/// pair of words.
+pub fn rhyme_key(syl: &Syllable) -> String {
+    syl.rhyme().iter().map(|p| p.symbol()).collect()
+}
+
+/// word_rhyme_key provides the [rhyme_key] of a word's final stressed
+/// syllable, chosen the same way as in [scheme]. A word with no syllables has
+/// an empty key.
+pub fn word_rhyme_key(w: &Word) -> String {
+    rhyming_syllable(w).map(rhyme_key).unwrap_or_default()
+}
+
+// the syllable whose rhyme stands for the rhyme of the whole word
+fn rhyming_syllable(word: &Word) -> Option<&Syllable> {
+    let syls = word.syllables();
+    syls.iter()
+        .rev()
+        .find(|syl| syl.stress.unwrap_or(Stress::Stressed) == Stress::Stressed)
+        .or_else(|| syls.last())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accents::genam::word;
+
+    fn words(descs: &[&str]) -> Vec<Word> {
+        descs.iter().map(|desc| word(desc).unwrap()).collect()
+    }
+
+    #[test]
+    fn test_scheme_aabb() {
+        let stanza = words(&["kæt", "hæt", "dɔɡ", "lɔɡ"]);
+        assert_eq!(scheme(&stanza), vec!['a', 'a', 'b', 'b']);
+    }
+
+    #[test]
+    fn test_scheme_uses_stressed_syllable() {
+        let stanza = words(&["kæt", "dɔɡ", "ˈæk.ɹə.bæt", "kæt"]);
+        assert_eq!(scheme(&stanza), vec!['a', 'b', 'c', 'a']);
+    }
+
+    #[test]
+    fn test_rhyme_key() {
+        let cat = word("kæt").unwrap();
+        let hat = word("hæt").unwrap();
+        let cot = word("kɑt").unwrap();
+        assert_eq!(word_rhyme_key(&cat), "æt");
+        assert_eq!(word_rhyme_key(&cat), word_rhyme_key(&hat));
+        assert_ne!(word_rhyme_key(&cat), word_rhyme_key(&cot));
+        assert_eq!(
+            word_rhyme_key(&word("ˈæk.ɹə.bæt").unwrap()),
+            word_rhyme_key(&word("bæk").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_scheme_no_stressed_syllable() {
+        let stanza = words(&["bə.tæt", "kæt"]);
+        assert_eq!(scheme(&stanza), vec!['a', 'a']);
+    }
+}
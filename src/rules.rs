@@ -0,0 +1,116 @@
+//! Phonological rules over sequences of segments
+//!
+//! A phonological rule describes a change to segments that appear in a
+//! particular context, such as "vowels become nasalized before nasals". Rules
+//! here are written with underspecified segments: the target and contexts of
+//! a rule list only the features that matter, and any segment that
+//! [matches](crate::features::Segment::matches) them is affected.
+
+use crate::builders::Builder;
+use crate::features::Segment;
+
+/// A Rule changes every segment that matches its target and whose neighbors
+/// match its contexts.
+pub struct Rule {
+    /// An underspecified segment describing which segments the rule changes
+    pub target: Segment,
+    /// The change made to each matching segment
+    pub change: Builder,
+    /// An underspecified segment that must immediately precede the target, if
+    /// the rule has a left context
+    pub left_ctx: Option<Segment>,
+    /// An underspecified segment that must immediately follow the target, if
+    /// the rule has a right context
+    pub right_ctx: Option<Segment>,
+}
+
+impl Rule {
+    /// apply the rule to a sequence of segments.
+    ///
+    /// Matching is done against the sequence as it was before the rule was
+    /// applied, so the rule applies simultaneously at every matching position;
+    /// a change made at one position cannot create or destroy a context for
+    /// another.
+    pub fn apply(&self, word: &mut [Segment]) {
+        let positions: Vec<usize> = (0..word.len())
+            .filter(|&i| self.applies_at(word, i))
+            .collect();
+
+        for i in positions {
+            (self.change)(&mut word[i]);
+        }
+    }
+
+    fn applies_at(&self, word: &[Segment], i: usize) -> bool {
+        let left_ok = self
+            .left_ctx
+            .is_none_or(|ctx| i > 0 && ctx.matches(&word[i - 1]));
+        let right_ok = self.right_ctx.is_none_or(|ctx| {
+            word.get(i + 1).is_some_and(|seg| ctx.matches(seg))
+        });
+
+        self.target.matches(&word[i]) && left_ok && right_ok
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accents::genam::phoneme;
+    use crate::builders::consonants::nasal;
+    use crate::builders::vowels::nasalized;
+    use crate::builders::SegmentBuilder;
+    use crate::features::UnaryFeature;
+    use crate::phoneme::Phoneme;
+
+    fn segs(ss: &[&str]) -> Vec<Segment> {
+        ss.iter()
+            .map(|s| match phoneme(s).unwrap() {
+                Phoneme::Monosegment(seg) => seg,
+                Phoneme::Disegment(..) => panic!("expected monosegment"),
+            })
+            .collect()
+    }
+
+    fn nasalize_before_nasals() -> Rule {
+        Rule {
+            target: SegmentBuilder::vowel(&[], 'V'),
+            change: nasalized,
+            left_ctx: None,
+            right_ctx: Some(SegmentBuilder::consonant(&[nasal], 'N')),
+        }
+    }
+
+    #[test]
+    fn test_nasalize_vowels_before_nasals() {
+        let mut word = segs(&["p", "æ", "n"]);
+        nasalize_before_nasals().apply(&mut word);
+        assert_eq!(
+            word[1].autosegmental_features.nasal,
+            Some(UnaryFeature::Marked)
+        );
+    }
+
+    #[test]
+    fn test_rule_context_not_met() {
+        let mut word = segs(&["p", "æ", "t"]);
+        let original = word.clone();
+        nasalize_before_nasals().apply(&mut word);
+        assert_eq!(word, original);
+    }
+
+    #[test]
+    fn test_rule_left_context() {
+        let rule = Rule {
+            left_ctx: Some(SegmentBuilder::consonant(&[nasal], 'N')),
+            right_ctx: None,
+            ..nasalize_before_nasals()
+        };
+        let mut word = segs(&["m", "i", "t"]);
+        rule.apply(&mut word);
+        assert_eq!(
+            word[1].autosegmental_features.nasal,
+            Some(UnaryFeature::Marked)
+        );
+    }
+}
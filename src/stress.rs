@@ -40,6 +40,21 @@ impl Stress {
         }
     }
 
+    /// to_ternary converts four-level stress to three-level stress.
+    ///
+    /// ReducedStress becomes Reduced,
+    /// Unstressed and SecondaryStress become Unstressed,
+    /// Stressed remains Stressed.
+    pub fn to_ternary(self) -> TernaryStress {
+        match self {
+            Stress::ReducedStress => TernaryStress::Reduced,
+            Stress::Unstressed | Stress::SecondaryStress => {
+                TernaryStress::Unstressed
+            }
+            Stress::Stressed => TernaryStress::Stressed,
+        }
+    }
+
     /// symbol provides the IPA symbol associated with the stress level, if a
     /// symbol is associated with that level of stress.
     ///
@@ -53,6 +68,33 @@ impl Stress {
             Stress::Stressed => Some('ˈ'),
         }
     }
+
+    /// from_cmu converts a CMU Pronouncing Dictionary stress digit to a
+    /// stress level.
+    ///
+    /// 0 becomes Unstressed, 1 becomes Stressed, and 2 becomes
+    /// SecondaryStress. Any other digit has no stress level.
+    pub fn from_cmu(n: u8) -> Option<Stress> {
+        match n {
+            0 => Some(Stress::Unstressed),
+            1 => Some(Stress::Stressed),
+            2 => Some(Stress::SecondaryStress),
+            _ => None,
+        }
+    }
+
+    /// to_cmu converts a stress level to a CMU Pronouncing Dictionary stress
+    /// digit.
+    ///
+    /// The CMU dictionary has no reduced stress level, so this conversion is
+    /// lossy: ReducedStress and Unstressed both become 0.
+    pub fn to_cmu(self) -> u8 {
+        match self {
+            Stress::ReducedStress | Stress::Unstressed => 0,
+            Stress::Stressed => 1,
+            Stress::SecondaryStress => 2,
+        }
+    }
 }
 
 /// BinaryStress represents two levels of syllable emphasis
@@ -63,3 +105,70 @@ pub enum BinaryStress {
     /// The syllable is more emphasized than surrounding syllables
     Stressed,
 }
+
+impl BinaryStress {
+    /// symbol provides the IPA symbol associated with the stress level, if a
+    /// symbol is associated with that level of stress.
+    ///
+    /// Unstressed has no marker.
+    /// Stressed is marked with 'ˈ'.
+    pub fn symbol(&self) -> Option<char> {
+        match self {
+            BinaryStress::Unstressed => None,
+            BinaryStress::Stressed => Some('ˈ'),
+        }
+    }
+}
+
+/// TernaryStress represents three levels of syllable emphasis, separating
+/// reduced syllables from other unstressed syllables. Use
+/// [Stress::to_ternary] to reduce four-level stress to ternary stress.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy)]
+pub enum TernaryStress {
+    /// The syllable is not only least emphasized, but also reduced
+    Reduced,
+    /// The syllable is not the most prominent in a word, whether or not it
+    /// carries secondary stress
+    Unstressed,
+    /// The syllable is more emphasized than surrounding syllables
+    Stressed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cmu_round_trip() {
+        for n in 0..=2 {
+            assert_eq!(Stress::from_cmu(n).map(Stress::to_cmu), Some(n));
+        }
+    }
+
+    #[test]
+    fn test_from_cmu_unknown() {
+        assert_eq!(Stress::from_cmu(3), None);
+    }
+
+    #[test]
+    fn test_to_cmu_reduced() {
+        assert_eq!(Stress::ReducedStress.to_cmu(), 0);
+    }
+
+    #[test]
+    fn test_to_ternary() {
+        assert_eq!(Stress::ReducedStress.to_ternary(), TernaryStress::Reduced);
+        assert_eq!(Stress::Unstressed.to_ternary(), TernaryStress::Unstressed);
+        assert_eq!(
+            Stress::SecondaryStress.to_ternary(),
+            TernaryStress::Unstressed
+        );
+        assert_eq!(Stress::Stressed.to_ternary(), TernaryStress::Stressed);
+    }
+
+    #[test]
+    fn test_binary_stress_symbol() {
+        assert_eq!(BinaryStress::Unstressed.symbol(), None);
+        assert_eq!(BinaryStress::Stressed.symbol(), Some('ˈ'));
+    }
+}
@@ -6,8 +6,19 @@
 //! takes on meaning in relation to other syllables in the same word (lexical
 //! stress).
 
+use crate::builders::consonants::velarized;
+use crate::feature_classes::{is_fricative, is_stop, is_syllabic};
+use crate::features::accessors::{get_lateral, get_voice};
+use crate::features::{
+    BinaryFeature, LaryngealFeatures, Segment, UnaryFeature,
+};
 use crate::phoneme::Phoneme;
 use crate::stress::Stress;
+use crate::timing::relative_duration;
+use crate::tone::Tone;
+
+use std::error::Error;
+use std::fmt;
 
 /// A Syllable describes a structured collection of phonemes, what people commonly
 /// distinguish as the unit out of which words are constructed.
@@ -22,6 +33,9 @@ pub struct Syllable {
     /// A syllable's stress marks the level of stress that is put on the syllable.
     /// It is only meaningful in relation to surrounding syllables in the same word.
     pub stress: Option<Stress>,
+    /// A syllable's tone marks its pitch pattern in tonal languages. It is None
+    /// for syllables without lexical tone.
+    pub tone: Option<Tone>,
 }
 
 impl Syllable {
@@ -37,9 +51,55 @@ impl Syllable {
             nucleus,
             coda: coda.to_vec(),
             stress,
+            tone: None,
         }
     }
 
+    /// Create a new syllable from its constituent parts, checking that it is
+    /// well formed.
+    ///
+    /// The syllable must pass [Syllable::validate].
+    pub fn try_new(
+        onset: &[Phoneme],
+        nucleus: Phoneme,
+        coda: &[Phoneme],
+        stress: Option<Stress>,
+    ) -> Result<Syllable, SyllableError> {
+        let syl = Syllable::new(onset, nucleus, coda, stress);
+        syl.validate()?;
+        Ok(syl)
+    }
+
+    /// validate checks that a syllable is well formed: the nucleus must be a
+    /// vowel or syllabic consonant (+syllabic), and the onset and coda must
+    /// not contain any +syllabic phonemes.
+    /// These are the same invariants that
+    /// [from_accent](crate::builders::words::from_accent) enforces when
+    /// syllabizing a word.
+    ///
+    /// Syllables built directly (with [Syllable::new] or as a struct literal)
+    /// are not checked, so validate can be used to check them afterward.
+    pub fn validate(&self) -> Result<(), SyllableError> {
+        if !is_syllabic(self.nucleus) {
+            return Err(SyllableError::new(&format!(
+                "BadSylStructure: nucleus {} is not a vowel",
+                self.nucleus.symbol()
+            )));
+        }
+        if let Some(p) = self
+            .onset
+            .iter()
+            .chain(&self.coda)
+            .find(|p| is_syllabic(**p))
+        {
+            return Err(SyllableError::new(&format!(
+                "BadSylStructure: vowel {} outside of nucleus",
+                p.symbol()
+            )));
+        }
+        Ok(())
+    }
+
     /// The rhyme is the nucleus and coda of a syllable together.
     pub fn rhyme(&self) -> Vec<Phoneme> {
         let mut vec = vec![self.nucleus];
@@ -56,6 +116,31 @@ impl Syllable {
         vec
     }
 
+    /// onset_len is the number of phonemes in the onset of a syllable.
+    pub fn onset_len(&self) -> usize {
+        self.onset.len()
+    }
+
+    /// coda_len is the number of phonemes in the coda of a syllable.
+    pub fn coda_len(&self) -> usize {
+        self.coda.len()
+    }
+
+    /// morae is the number of morae (units of syllable weight) in a syllable.
+    ///
+    /// A light syllable has 1 mora and a heavy syllable has 2. A syllable is
+    /// heavy when its rhyme branches: when its nucleus is a diphthong (a
+    /// disegment) or when it has a coda. Onsets never add weight. Vowel
+    /// length is not represented in phonemes, so a monosegment nucleus always
+    /// counts as short, and no syllable is counted as superheavy (3 morae).
+    pub fn morae(&self) -> usize {
+        if self.nucleus.is_disegment() || !self.coda.is_empty() {
+            2
+        } else {
+            1
+        }
+    }
+
     /// symbols returns the symbolic representation of a syllable's phonemes as a
     /// single String.
     ///
@@ -66,12 +151,140 @@ impl Syllable {
             .iter()
             .fold(String::new(), |acc, p| acc + &p.symbol())
     }
+
+    /// assimilate_coda_voicing makes adjacent obstruents in the coda agree in
+    /// voicing, as in English plurals ("cats" with \[s\], "dogs" with \[z\]).
+    ///
+    /// Within each run of adjacent obstruents (stops, fricatives, affricates)
+    /// in the coda, the first obstruent's voice value spreads rightward onto
+    /// the obstruents that follow it. A sonorant blocks spreading and begins a
+    /// new run.
+    ///
+    /// Voicing spreads rightward, from the stem consonant onto a suffix, since
+    /// that is the direction of English plural and past tense agreement.
+    /// Spreading the last obstruent's voicing leftward would instead devoice
+    /// the stem, turning "dogs" into \[dɔks\] rather than \[dɔɡz\].
+    ///
+    /// An obstruent whose voicing changes is also given the symbol of its
+    /// voicing partner ('s' becomes 'z', 'd' becomes 't'). Segments with no
+    /// partner in the IPA consonant chart keep their symbols.
+    pub fn assimilate_coda_voicing(&mut self) {
+        let mut spreading = None;
+
+        for phoneme in self.coda.iter_mut() {
+            if !(is_stop(*phoneme) || is_fricative(*phoneme)) {
+                spreading = None;
+                continue;
+            }
+            match spreading {
+                None => spreading = Some(last_voice(*phoneme)),
+                Some(voice) => set_voice(phoneme, voice),
+            }
+        }
+    }
+
+    /// darken_coda_l velarizes every lateral in the coda of a syllable, as in
+    /// the "dark l" of GenAm "feel" \[fiɫ\]. Laterals in the onset are left
+    /// clear.
+    ///
+    /// Each coda lateral gains a (+back) dorsal node (see
+    /// [velarized](crate::builders::consonants::velarized)), and a lateral
+    /// written 'l' is given the symbol 'ɫ'.
+    pub fn darken_coda_l(&mut self) {
+        for phoneme in self.coda.iter_mut() {
+            if let Phoneme::Monosegment(seg) = phoneme {
+                if get_lateral(seg) == Some(UnaryFeature::Marked) {
+                    velarized(seg);
+                    if seg.symbol == 'l' {
+                        seg.symbol = 'ɫ';
+                    }
+                }
+            }
+        }
+    }
+
+    /// estimated_duration sums the relative durations of a syllable's
+    /// phonemes. See [relative_duration] for the heuristic this is based on.
+    pub fn estimated_duration(&self) -> f64 {
+        self.phonemes().iter().map(relative_duration).sum()
+    }
+}
+
+/// An error created when a syllable's phonemes do not form a well-formed
+/// syllable.
+#[derive(Debug)]
+pub struct SyllableError {
+    msg: String,
+}
+
+impl SyllableError {
+    fn new(msg: &str) -> SyllableError {
+        SyllableError {
+            msg: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for SyllableError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl Error for SyllableError {}
+
+fn last_voice(p: Phoneme) -> Option<BinaryFeature> {
+    get_voice(&p.last_segment())
+}
+
+// Voiceless and voiced obstruents that differ only in voicing.
+const VOICING_PAIRS: [(char, char); 11] = [
+    ('p', 'b'),
+    ('t', 'd'),
+    ('k', 'ɡ'),
+    ('c', 'ɟ'),
+    ('q', 'ɢ'),
+    ('ɸ', 'β'),
+    ('f', 'v'),
+    ('θ', 'ð'),
+    ('s', 'z'),
+    ('ʃ', 'ʒ'),
+    ('x', 'ɣ'),
+];
+
+fn voiced_symbol(symbol: char, voice: Option<BinaryFeature>) -> char {
+    VOICING_PAIRS
+        .iter()
+        .find_map(|&(vl, vd)| match voice {
+            Some(BinaryFeature::Marked) if symbol == vl => Some(vd),
+            Some(BinaryFeature::Unmarked) if symbol == vd => Some(vl),
+            _ => None,
+        })
+        .unwrap_or(symbol)
+}
+
+fn set_voice(p: &mut Phoneme, voice: Option<BinaryFeature>) {
+    let set = |seg: &mut Segment| {
+        seg.autosegmental_features
+            .laryngeal
+            .get_or_insert(LaryngealFeatures::default())
+            .voice = voice;
+        seg.symbol = voiced_symbol(seg.symbol, voice);
+    };
+    match p {
+        Phoneme::Monosegment(seg) => set(seg),
+        Phoneme::Disegment(seg1, seg2) => {
+            set(seg1);
+            set(seg2);
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::accents::genam::phoneme;
+    use crate::feature_classes;
     use crate::phoneme::Phoneme;
     use crate::stress::Stress;
 
@@ -81,6 +294,7 @@ mod tests {
             nucleus: phon("ɑ"),
             coda: vec![phon("p")],
             stress: Some(Stress::Stressed),
+            tone: None,
         }
     }
 
@@ -101,6 +315,49 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_try_new() {
+        let syl = Syllable::try_new(
+            &[phon("p"), phon("ɹ")],
+            phon("ɑ"),
+            &[phon("p")],
+            Some(Stress::Stressed),
+        );
+        assert_eq!(syl.unwrap(), test_syl());
+    }
+
+    #[test]
+    fn test_try_new_vowel_in_coda() {
+        let syl =
+            Syllable::try_new(&[phon("p")], phon("ɑ"), &[phon("i")], None);
+        assert!(syl.is_err());
+    }
+
+    #[test]
+    fn test_try_new_consonant_nucleus() {
+        let syl = Syllable::try_new(&[phon("p")], phon("t"), &[], None);
+        assert!(syl.is_err());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(test_syl().validate().is_ok());
+        let vowel_in_onset = Syllable {
+            onset: vec![phon("i"), phon("p")],
+            ..test_syl()
+        };
+        let err = vowel_in_onset.validate().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "BadSylStructure: vowel i outside of nucleus"
+        );
+        let consonant_nucleus = Syllable {
+            nucleus: phon("p"),
+            ..test_syl()
+        };
+        assert!(consonant_nucleus.validate().is_err());
+    }
+
     #[test]
     fn test_rhyme() {
         let test_syl = test_syl();
@@ -116,9 +373,111 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_cluster_lengths() {
+        let splints = Syllable::new(
+            &[phon("s"), phon("p"), phon("l")],
+            phon("ɪ"),
+            &[phon("n"), phon("t"), phon("s")],
+            None,
+        );
+        assert_eq!(splints.onset_len(), 3);
+        assert_eq!(splints.coda_len(), 3);
+
+        let a = Syllable::new(&[], phon("ɑ"), &[], None);
+        assert_eq!(a.onset_len(), 0);
+        assert_eq!(a.coda_len(), 0);
+    }
+
+    #[test]
+    fn test_morae() {
+        let light = Syllable::new(&[phon("b")], phon("ə"), &[], None);
+        assert_eq!(light.morae(), 1);
+        let closed = Syllable::new(&[], phon("æ"), &[phon("t")], None);
+        assert_eq!(closed.morae(), 2);
+        let diphthong = Syllable::new(&[phon("b")], phon("a͡ɪ"), &[], None);
+        assert_eq!(diphthong.morae(), 2);
+        let both = Syllable::new(&[], phon("a͡ɪ"), &[phon("t")], None);
+        assert_eq!(both.morae(), 2);
+    }
+
     #[test]
     fn test_symbols() {
         let test_syl = test_syl();
         assert_eq!(test_syl.symbols(), String::from("pɹɑp"))
     }
+
+    #[test]
+    fn test_assimilate_coda_voicing() {
+        let mut dogs = Syllable::new(
+            &[phon("d")],
+            phon("ɔ"),
+            &[phon("ɡ"), phon("s")],
+            None,
+        );
+        dogs.assimilate_coda_voicing();
+        assert!(feature_classes::is_voiced(dogs.coda[1]));
+        assert!(feature_classes::is_voiced(dogs.coda[0]));
+        assert_eq!(dogs.symbols(), "dɔɡz");
+
+        let mut cats = Syllable::new(
+            &[phon("k")],
+            phon("æ"),
+            &[phon("t"), phon("s")],
+            None,
+        );
+        cats.assimilate_coda_voicing();
+        assert!(!feature_classes::is_voiced(cats.coda[1]));
+        assert_eq!(cats.symbols(), "kæts");
+
+        let mut bets = Syllable::new(
+            &[phon("b")],
+            phon("ɛ"),
+            &[phon("t"), phon("z")],
+            None,
+        );
+        bets.assimilate_coda_voicing();
+        assert!(!feature_classes::is_voiced(bets.coda[1]));
+        assert_eq!(bets.symbols(), "bɛts");
+    }
+
+    #[test]
+    fn test_assimilate_coda_voicing_sonorant_blocks() {
+        let mut syl = Syllable::new(
+            &[],
+            phon("ɪ"),
+            &[phon("d"), phon("n"), phon("s")],
+            None,
+        );
+        syl.assimilate_coda_voicing();
+        assert!(!feature_classes::is_voiced(syl.coda[2]));
+    }
+
+    #[test]
+    fn test_darken_coda_l() {
+        use crate::features::accessors::get_dorsal;
+
+        let mut lull =
+            Syllable::new(&[phon("l")], phon("ʌ"), &[phon("l")], None);
+        lull.darken_coda_l();
+        assert_eq!(lull.onset[0], phon("l"));
+        assert_eq!(lull.coda[0].symbol(), "ɫ");
+        let dorsal = |p: Phoneme| get_dorsal(&p.first_segment());
+        assert_eq!(dorsal(lull.onset[0]), None);
+        assert_eq!(
+            dorsal(lull.coda[0]).and_then(|d| d.back),
+            Some(BinaryFeature::Marked)
+        );
+        let mut cat =
+            Syllable::new(&[phon("k")], phon("æ"), &[phon("t")], None);
+        cat.darken_coda_l();
+        assert_eq!(cat.coda[0], phon("t"));
+    }
+
+    #[test]
+    fn test_estimated_duration() {
+        let test_syl = test_syl();
+        let shorter = Syllable::new(&[phon("p")], phon("ɑ"), &[], None);
+        assert!(test_syl.estimated_duration() > shorter.estimated_duration());
+    }
 }
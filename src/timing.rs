@@ -0,0 +1,64 @@
+//! Relative durations of phonemes
+//!
+//! Timing provides rough estimates of how long phonemes last relative to one
+//! another, for uses like crude speech synthesis where some notion of timing
+//! is needed. These estimates are a heuristic based on the broad class of a
+//! phoneme (vowel, stop, fricative, ...), not a measurement of physical
+//! duration. Actual durations vary with speaker, rate, stress, and context.
+
+use crate::feature_classes::*;
+use crate::phoneme::Phoneme;
+
+/// relative_duration provides a duration multiplier for a phoneme, where a
+/// monophthong vowel is 1.0.
+///
+/// Vowels are longer than consonants, and diphthongs (vowel disegments) are
+/// longer than monophthongs. Syllabic consonants ('n̩') fill the nucleus of a
+/// syllable, and so are timed as vowels. Among consonants, stops are
+/// shortest, fricatives and affricates are longer, and sonorant consonants
+/// fall in between.
+pub fn relative_duration(p: &Phoneme) -> f64 {
+    let p = *p;
+    if is_syllabic(p) {
+        match p {
+            Phoneme::Monosegment(_) => 1.0,
+            Phoneme::Disegment(..) => 1.5,
+        }
+    } else if is_affricate(p) {
+        0.9
+    } else if is_stop(p) {
+        0.5
+    } else if is_fricative(p) {
+        0.8
+    } else {
+        0.7
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accents::genam::phoneme;
+
+    fn duration(s: &str) -> f64 {
+        relative_duration(&phoneme(s).unwrap())
+    }
+
+    #[test]
+    fn test_vowel_longer_than_consonant() {
+        assert!(duration("ɑ") > duration("t"));
+        assert!(duration("ɑ") > duration("s"));
+        assert!(duration("ɑ") > duration("n"));
+    }
+
+    #[test]
+    fn test_diphthong_longer_than_monophthong() {
+        assert!(duration("a͡ɪ") > duration("ɑ"));
+    }
+
+    #[test]
+    fn test_stop_shorter_than_fricative() {
+        assert!(duration("s") > duration("t"));
+        assert!(duration("t͡ʃ") > duration("t"));
+    }
+}
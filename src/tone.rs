@@ -0,0 +1,152 @@
+//! Lexical tone for syllables
+//!
+//! Tone marks the pitch pattern of a syllable in tonal languages, where pitch
+//! distinguishes words the way that phonemes do. Tone is optional on a
+//! syllable; languages without lexical tone (like English) leave it as None.
+//!
+//! Tones are described here by their shape (level or contour) rather than by
+//! the numbered tone categories of any particular language, so that tone marks
+//! written in different notations resolve to the same tone.
+
+/// Tone is the pitch pattern of a syllable, either a level pitch or a contour.
+#[derive(PartialEq, Eq, Debug, Clone, Copy, Hash)]
+pub enum Tone {
+    /// A level tone at the top of the speaker's pitch range: '˥'
+    ExtraHigh,
+    /// A level tone above the middle of the speaker's pitch range: '˦'
+    High,
+    /// A level tone in the middle of the speaker's pitch range: '˧'
+    Mid,
+    /// A level tone below the middle of the speaker's pitch range: '˨'
+    Low,
+    /// A level tone at the bottom of the speaker's pitch range: '˩'
+    ExtraLow,
+    /// A contour tone that moves upward in pitch: '˧˥'
+    Rising,
+    /// A contour tone that moves downward in pitch: '˥˩'
+    Falling,
+    /// A contour tone that falls and then rises: '˨˩˦'
+    Dipping,
+    /// A contour tone that rises and then falls: '˧˦˨'
+    Peaking,
+}
+
+impl Tone {
+    /// from_symbols reads a tone from a sequence of tone marks.
+    ///
+    /// Two notations are recognized:
+    /// - Chao tone letters (˥˦˧˨˩), where a single letter is a level tone and
+    ///   a sequence of letters is a contour classified by its shape
+    /// - a single combining tone diacritic, as written over a vowel:
+    ///   double acute (U+030B) ExtraHigh, acute (U+0301) High,
+    ///   macron (U+0304) Mid, grave (U+0300) Low, double grave (U+030F)
+    ///   ExtraLow, caron (U+030C) Rising, circumflex (U+0302) Falling
+    ///
+    /// Any other sequence of symbols is not a tone.
+    pub fn from_symbols(s: &str) -> Option<Tone> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            match c {
+                '\u{030B}' => return Some(Tone::ExtraHigh),
+                '\u{0301}' => return Some(Tone::High),
+                '\u{0304}' => return Some(Tone::Mid),
+                '\u{0300}' => return Some(Tone::Low),
+                '\u{030F}' => return Some(Tone::ExtraLow),
+                '\u{030C}' => return Some(Tone::Rising),
+                '\u{0302}' => return Some(Tone::Falling),
+                _ => {}
+            }
+        }
+
+        let levels = s.chars().map(chao_level).collect::<Option<Vec<_>>>()?;
+        let first = *levels.first()?;
+        let mut rose = false;
+        let mut fell = false;
+        let mut fell_after_rise = false;
+        let mut rose_after_fall = false;
+        for pair in levels.windows(2) {
+            if pair[1] > pair[0] {
+                rose_after_fall |= fell;
+                rose = true;
+            } else if pair[1] < pair[0] {
+                fell_after_rise |= rose;
+                fell = true;
+            }
+        }
+
+        match (rose, fell) {
+            (false, false) => match first {
+                5 => Some(Tone::ExtraHigh),
+                4 => Some(Tone::High),
+                3 => Some(Tone::Mid),
+                2 => Some(Tone::Low),
+                _ => Some(Tone::ExtraLow),
+            },
+            (true, false) => Some(Tone::Rising),
+            (false, true) => Some(Tone::Falling),
+            (true, true) if rose_after_fall && !fell_after_rise => {
+                Some(Tone::Dipping)
+            }
+            (true, true) if fell_after_rise && !rose_after_fall => {
+                Some(Tone::Peaking)
+            }
+            _ => None,
+        }
+    }
+
+    /// is_tone_symbol is true for the symbols that [Tone::from_symbols]
+    /// reads: Chao tone letters and combining tone diacritics.
+    pub fn is_tone_symbol(c: char) -> bool {
+        chao_level(c).is_some()
+            || "\u{030B}\u{0301}\u{0304}\u{0300}\u{030F}\u{030C}\u{0302}"
+                .contains(c)
+    }
+}
+
+// The pitch level (1-5, low to high) of a Chao tone letter
+fn chao_level(c: char) -> Option<u8> {
+    match c {
+        '˥' => Some(5),
+        '˦' => Some(4),
+        '˧' => Some(3),
+        '˨' => Some(2),
+        '˩' => Some(1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_tone_letters() {
+        assert_eq!(Tone::from_symbols("˥"), Some(Tone::ExtraHigh));
+        assert_eq!(Tone::from_symbols("˧"), Some(Tone::Mid));
+        assert_eq!(Tone::from_symbols("˩"), Some(Tone::ExtraLow));
+        assert_eq!(Tone::from_symbols("˥˥"), Some(Tone::ExtraHigh));
+    }
+
+    #[test]
+    fn test_contour_tone_letters() {
+        assert_eq!(Tone::from_symbols("˧˥"), Some(Tone::Rising));
+        assert_eq!(Tone::from_symbols("˥˩"), Some(Tone::Falling));
+        assert_eq!(Tone::from_symbols("˨˩˦"), Some(Tone::Dipping));
+        assert_eq!(Tone::from_symbols("˧˦˨"), Some(Tone::Peaking));
+    }
+
+    #[test]
+    fn test_tone_diacritics() {
+        assert_eq!(Tone::from_symbols("\u{0301}"), Some(Tone::High));
+        assert_eq!(Tone::from_symbols("\u{0300}"), Some(Tone::Low));
+        assert_eq!(Tone::from_symbols("\u{030C}"), Some(Tone::Rising));
+    }
+
+    #[test]
+    fn test_not_a_tone() {
+        assert_eq!(Tone::from_symbols(""), None);
+        assert_eq!(Tone::from_symbols("a"), None);
+        assert_eq!(Tone::from_symbols("\u{0301}\u{0300}"), None);
+        assert_eq!(Tone::from_symbols("˥˩˥˩"), None);
+    }
+}
@@ -3,14 +3,20 @@
 //! A word is an ordered list of syllables. It also provides functions for
 //! flattening the sounds and stresses of a word into lists.
 
+use crate::distance::phoneme_edit_distance;
+use crate::foot::{scan, Foot};
 use crate::phoneme::Phoneme;
-use crate::stress::Stress;
+use crate::stress::{BinaryStress, Stress};
 use crate::syllable::Syllable;
+use crate::timing::relative_duration;
+
+use std::error::Error;
+use std::fmt;
 
 /// A word is a collection of syllables. It represents a spoken word, or
 /// perhaps the pronunciation information that would correspond to a written
 /// word.
-#[derive(PartialEq, Eq, Debug)]
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub struct Word(Vec<Syllable>);
 
 impl Word {
@@ -19,6 +25,169 @@ impl Word {
         Word(syllables.to_vec())
     }
 
+    /// Create a word from a collection of syllables, normalizing and checking
+    /// its stress.
+    ///
+    /// Because lexical stress is only meaningful between syllables, the stress
+    /// of a single-syllable word is normalized to None. A word with no
+    /// syllables, or with more than one syllable carrying primary stress
+    /// (Stressed), is rejected with a [WordError].
+    pub fn try_from_syllables(
+        syllables: Vec<Syllable>,
+    ) -> Result<Word, WordError> {
+        let mut syllables = syllables;
+
+        match syllables.len() {
+            0 => return Err(WordError::new("EmptyWord: no syllables")),
+            1 => syllables[0].stress = None,
+            _ => {
+                let primary = syllables
+                    .iter()
+                    .filter(|syl| syl.stress == Some(Stress::Stressed))
+                    .count();
+                if primary > 1 {
+                    return Err(WordError::new(&format!(
+                        "BadStress: {} syllables with primary stress",
+                        primary
+                    )));
+                }
+            }
+        }
+
+        Ok(Word(syllables))
+    }
+
+    /// with_primary_stress moves primary stress to the syllable at index, as in
+    /// stress shift under affixation ("ˈfo͡ʊ.tə.ɡɹæf" to "fəˈtɑ.ɡɹə.fi").
+    ///
+    /// The syllable at index becomes Stressed, and any other syllable that was
+    /// Stressed is demoted to SecondaryStress. A single-syllable word is
+    /// returned unchanged, since its stress is always None. An index past the
+    /// last syllable is rejected with a [StressError].
+    pub fn with_primary_stress(
+        self,
+        index: usize,
+    ) -> Result<Word, StressError> {
+        let mut syllables = self.0;
+
+        if index >= syllables.len() {
+            return Err(StressError::new(&format!(
+                "BadIndex: no syllable {} in a word of {} syllables",
+                index,
+                syllables.len()
+            )));
+        }
+        if syllables.len() == 1 {
+            return Ok(Word(syllables));
+        }
+
+        for syl in syllables.iter_mut() {
+            if syl.stress == Some(Stress::Stressed) {
+                syl.stress = Some(Stress::SecondaryStress);
+            }
+        }
+        syllables[index].stress = Some(Stress::Stressed);
+
+        Ok(Word(syllables))
+    }
+
+    /// without_stress removes the stress information from every syllable of a
+    /// word, for comparisons where stress does not matter.
+    pub fn without_stress(self) -> Word {
+        Word(
+            self.0
+                .into_iter()
+                .map(|syl| Syllable {
+                    stress: None,
+                    ..syl
+                })
+                .collect(),
+        )
+    }
+
+    /// concat joins two words into a compound, appending the syllables of
+    /// other to those of self with English compound stress: the first word
+    /// keeps its primary stress, and the primary stress of the second word is
+    /// demoted to SecondaryStress ("ˈblæk" + "ˈbɝd" to "ˈblæk.ˌbɝd").
+    ///
+    /// The single syllable of a monosyllable has no stress of its own (see
+    /// [Word::try_from_syllables]), so it is treated as Stressed. Use
+    /// [Word::concat_raw] to keep every stress unchanged.
+    pub fn concat(self, other: Word) -> Word {
+        let demoted = other
+            .stress_monosyllable()
+            .0
+            .into_iter()
+            .map(|syl| match syl.stress {
+                Some(Stress::Stressed) => Syllable {
+                    stress: Some(Stress::SecondaryStress),
+                    ..syl
+                },
+                _ => syl,
+            })
+            .collect();
+        self.stress_monosyllable().concat_raw(Word(demoted))
+    }
+
+    /// concat_raw joins two words by appending the syllables of other to
+    /// those of self, keeping every stress unchanged. Unlike [Word::concat],
+    /// the result may have two primary stresses, or unstressed syllables from
+    /// monosyllables.
+    pub fn concat_raw(self, other: Word) -> Word {
+        let mut syllables = self.0;
+        syllables.extend(other.0);
+        Word(syllables)
+    }
+
+    fn stress_monosyllable(self) -> Word {
+        let mut syllables = self.0;
+        if let [syl] = syllables.as_mut_slice() {
+            syl.stress.get_or_insert(Stress::Stressed);
+        }
+        Word(syllables)
+    }
+
+    /// split_disegments splits every disegment in a word (affricates,
+    /// diphthongs) into its two monosegments, keeping each in its syllable.
+    ///
+    /// A split affricate stays in its onset or coda. A syllable's nucleus can
+    /// only hold one phoneme, so a split diphthong keeps its first segment
+    /// as the nucleus and moves its second segment (the offglide) to the
+    /// start of the coda: "ba͡ɪt" becomes b-a-ɪt. The offglide is +syllabic,
+    /// so the resulting syllables are not valid by the rules of
+    /// [Syllable::try_new](crate::syllable::Syllable::try_new), and a word
+    /// description built from them will not parse back to the same word.
+    pub fn split_disegments(self) -> Word {
+        let split_all = |ps: Vec<Phoneme>| -> Vec<Phoneme> {
+            ps.into_iter().flat_map(Phoneme::split).collect()
+        };
+        Word(
+            self.0
+                .into_iter()
+                .map(|syl| {
+                    let mut nucleus = syl.nucleus.split().into_iter();
+                    let first = nucleus.next().unwrap_or(syl.nucleus);
+                    Syllable {
+                        onset: split_all(syl.onset),
+                        nucleus: first,
+                        coda: nucleus.chain(split_all(syl.coda)).collect(),
+                        ..syl
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    // borrowed view of a word's syllables for use within the crate
+    pub(crate) fn syllables(&self) -> &[Syllable] {
+        &self.0
+    }
+
+    /// iter walks the syllables of a word in order, without consuming the word.
+    pub fn iter(&self) -> std::slice::Iter<'_, Syllable> {
+        self.0.iter()
+    }
+
     /// phonemes combines the flattened phoneme sets of a word's syllables into
     /// a single vector. The structure (onset-nucleus-coda) of the syllables is
     /// lost in this transformation.
@@ -32,6 +201,47 @@ impl Word {
         vec
     }
 
+    /// iter_phonemes walks the phonemes of a word's syllables in order
+    /// (onset, nucleus, coda), without consuming the word.
+    pub fn iter_phonemes(&self) -> impl Iterator<Item = Phoneme> + '_ {
+        self.0.iter().flat_map(|syl| {
+            syl.onset
+                .iter()
+                .copied()
+                .chain(std::iter::once(syl.nucleus))
+                .chain(syl.coda.iter().copied())
+        })
+    }
+
+    /// timed_phonemes spreads a total duration (in milliseconds) across the
+    /// phonemes of a word, in proportion to their
+    /// [relative_duration](crate::timing::relative_duration).
+    ///
+    /// Each phoneme is returned with its start and end time. The phonemes are
+    /// contiguous: each one starts where the one before it ends, the first
+    /// starts at 0, and the last ends at total_ms.
+    pub fn timed_phonemes(&self, total_ms: f64) -> Vec<(Phoneme, f64, f64)> {
+        let total_weight: f64 =
+            self.iter_phonemes().map(|p| relative_duration(&p)).sum();
+        let mut start = 0.0;
+        let mut elapsed_weight = 0.0;
+        let mut timed: Vec<(Phoneme, f64, f64)> = self
+            .iter_phonemes()
+            .map(|p| {
+                elapsed_weight += relative_duration(&p);
+                let end = total_ms * elapsed_weight / total_weight;
+                let entry = (p, start, end);
+                start = end;
+                entry
+            })
+            .collect();
+        // guard against rounding error at the end of the word
+        if let Some(last) = timed.last_mut() {
+            last.2 = total_ms;
+        }
+        timed
+    }
+
     /// stresses provides the list of stress levels corresponding to each syl
     /// in a word.
     pub fn stresses(self) -> Vec<Stress> {
@@ -46,11 +256,120 @@ impl Word {
         vec
     }
 
+    /// primary_stress_index provides the index of the first syllable in a word
+    /// that carries primary stress (Stressed), if there is one.
+    pub fn primary_stress_index(&self) -> Option<usize> {
+        self.0
+            .iter()
+            .position(|syl| syl.stress == Some(Stress::Stressed))
+    }
+
+    /// has_stress tests whether any syllable in a word is stressed, meaning it
+    /// is marked SecondaryStress or higher.
+    ///
+    /// Syllables with no stress information (as in a monosyllable) do not
+    /// count as stressed.
+    pub fn has_stress(&self) -> bool {
+        self.0
+            .iter()
+            .filter_map(|syl| syl.stress)
+            .any(|stress| stress >= Stress::SecondaryStress)
+    }
+
+    /// has_stress_clash tests whether two consecutive syllables in a word are
+    /// both stressed, meaning they are marked SecondaryStress or higher (they
+    /// are Stressed as binary stress). English tends to avoid such stress
+    /// clashes, so this flags awkward stress assignments, as in compounds.
+    ///
+    /// As with [Word::has_stress], syllables with no stress information do
+    /// not count as stressed.
+    pub fn has_stress_clash(&self) -> bool {
+        let is_stressed = |syl: &Syllable| {
+            syl.stress.map(Stress::to_binary_stress)
+                == Some(BinaryStress::Stressed)
+        };
+        self.0
+            .windows(2)
+            .any(|pair| is_stressed(&pair[0]) && is_stressed(&pair[1]))
+    }
+
+    /// stress_pattern provides a compact stress signature for a word, with one
+    /// character per syllable.
+    ///
+    /// The characters are the numeric stress marks accepted by
+    /// [from_accent](crate::builders::words::from_accent): '1' (Stressed),
+    /// '2' (SecondaryStress), '3' (Unstressed), and '4' (ReducedStress). A
+    /// syllable with no stress information (as in a monosyllable) is '1'.
+    pub fn stress_pattern(&self) -> String {
+        self.0
+            .iter()
+            .map(|syl| match syl.stress.unwrap_or(Stress::Stressed) {
+                Stress::Stressed => '1',
+                Stress::SecondaryStress => '2',
+                Stress::Unstressed => '3',
+                Stress::ReducedStress => '4',
+            })
+            .collect()
+    }
+
+    /// binary_stress_pattern provides a stress signature for a word reduced
+    /// to binary stress, with one character per syllable: 'S' (Stressed) or
+    /// 'u' (Unstressed).
+    ///
+    /// As with [Word::stress_pattern], a syllable with no stress information
+    /// is treated as stressed.
+    pub fn binary_stress_pattern(&self) -> String {
+        self.0
+            .iter()
+            .map(|syl| {
+                match syl.stress.unwrap_or(Stress::Stressed).to_binary_stress()
+                {
+                    BinaryStress::Stressed => 'S',
+                    BinaryStress::Unstressed => 'u',
+                }
+            })
+            .collect()
+    }
+
+    /// mora_count is the total number of morae in a word, the sum of
+    /// [Syllable::morae] over its syllables.
+    pub fn mora_count(&self) -> usize {
+        self.0.iter().map(Syllable::morae).sum()
+    }
+
+    /// feet groups the syllables of a word into metrical feet by their binary
+    /// stress, for scansion.
+    ///
+    /// Syllables are grouped greedily from left to right (see
+    /// [scan](crate::foot::scan)). Syllables left over at the end of the word
+    /// that do not form a foot are returned as the remainder. As with
+    /// [Word::binary_stress_pattern], a syllable with no stress information is
+    /// treated as stressed.
+    pub fn feet(&self) -> (Vec<Foot>, Vec<BinaryStress>) {
+        let stresses: Vec<BinaryStress> = self
+            .0
+            .iter()
+            .map(|syl| {
+                syl.stress.unwrap_or(Stress::Stressed).to_binary_stress()
+            })
+            .collect();
+        scan(&stresses)
+    }
+
     /// symbols returns a textual representation of a syllabized word
     ///
     /// Syllables are separated by the '.' character, except for syllables that
     /// begin with an IPA stress mark, which serves as a syllable separator in
     /// place of the dot.
+    ///
+    /// Stressed and SecondaryStress syllables are rendered with 'ˈ' and 'ˌ',
+    /// so a word parsed from IPA stress marks renders back to the same
+    /// description. IPA has no mark for ReducedStress, so those syllables are
+    /// rendered with the number '4' in place of the dot, as in
+    /// [from_accent](crate::builders::words::from_accent) descriptions
+    /// ("ˈti4tə"). Phonemes are rendered with their canonical symbols, so a
+    /// word parsed from an alternative spelling (like "r" for "ɹ") renders with
+    /// the canonical spelling instead.
     pub fn symbols(self) -> String {
         if self.0.is_empty() {
             return String::from("");
@@ -59,11 +378,7 @@ impl Word {
         let mut syms = String::new();
 
         for (i, syl) in self.into_iter().enumerate() {
-            let separator = syl
-                .stress
-                .unwrap_or(Stress::Unstressed)
-                .symbol()
-                .unwrap_or('.');
+            let separator = stress_mark(syl.stress).unwrap_or('.');
             if i != 0 || separator != '.' {
                 syms.push(separator);
             }
@@ -72,8 +387,108 @@ impl Word {
 
         syms
     }
+
+    /// symbols_with_boundaries returns a textual representation of a
+    /// syllabized word in which every syllable boundary is marked.
+    ///
+    /// Every syllable after the first is preceded by the '.' character. A
+    /// syllable with an IPA stress mark has that mark placed after the dot
+    /// ("ə.ˈla͡ʊ"), rather than in place of it as in [Word::symbols]
+    /// ("əˈla͡ʊ"). The first syllable has no dot, but keeps its stress mark.
+    /// ReducedStress is marked with '4', as in [Word::symbols] ("ˈti.4tə").
+    pub fn symbols_with_boundaries(&self) -> String {
+        let mut syms = String::new();
+
+        for (i, syl) in self.0.iter().enumerate() {
+            if i != 0 {
+                syms.push('.');
+            }
+            if let Some(mark) = stress_mark(syl.stress) {
+                syms.push(mark);
+            }
+            syms.push_str(&syl.symbols());
+        }
+
+        syms
+    }
+}
+
+// The mark written before a syllable with the given stress, if any. IPA has
+// no mark for ReducedStress, so it is written with the number '4'.
+fn stress_mark(stress: Option<Stress>) -> Option<char> {
+    match stress? {
+        Stress::ReducedStress => Some('4'),
+        stress => stress.symbol(),
+    }
+}
+
+/// are_homophones tests whether two words are made of the same sequence of
+/// phonemes, ignoring stress and syllabification.
+pub fn are_homophones(a: &Word, b: &Word) -> bool {
+    a.iter_phonemes().eq(b.iter_phonemes())
 }
 
+/// near_homophone_score measures how close two words are in sound, from 0.0
+/// (nothing in common) to 1.0 (homophones).
+///
+/// The score is the [phoneme_edit_distance] between the words' phonemes,
+/// normalized by the length of the longer word and subtracted from 1. Like
+/// [are_homophones], it ignores stress and syllabification.
+pub fn near_homophone_score(a: &Word, b: &Word) -> f64 {
+    let a: Vec<Phoneme> = a.iter_phonemes().collect();
+    let b: Vec<Phoneme> = b.iter_phonemes().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - phoneme_edit_distance(&a, &b) as f64 / longest as f64
+}
+
+/// An error created when a collection of syllables does not form a
+/// well-formed word.
+#[derive(Debug)]
+pub struct WordError {
+    msg: String,
+}
+
+impl WordError {
+    fn new(msg: &str) -> WordError {
+        WordError {
+            msg: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for WordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl Error for WordError {}
+
+/// An error created when a change to the stress of a word cannot be made.
+#[derive(Debug)]
+pub struct StressError {
+    msg: String,
+}
+
+impl StressError {
+    fn new(msg: &str) -> StressError {
+        StressError {
+            msg: msg.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for StressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.msg)
+    }
+}
+
+impl Error for StressError {}
+
 impl From<Vec<Syllable>> for Word {
     fn from(syls: Vec<Syllable>) -> Word {
         Word(syls)
@@ -96,10 +511,20 @@ impl IntoIterator for Word {
     }
 }
 
+impl<'a> IntoIterator for &'a Word {
+    type Item = &'a Syllable;
+    type IntoIter = std::slice::Iter<'a, Syllable>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use super::*;
+    use crate::accents::genam;
     use crate::accents::genam::phoneme;
 
     fn mk_syl(
@@ -113,6 +538,7 @@ mod tests {
             nucleus,
             coda,
             stress: Some(stress),
+            tone: None,
         }
     }
 
@@ -145,6 +571,59 @@ mod tests {
         ])
     }
 
+    #[test]
+    fn test_try_from_syllables() {
+        let word = Word::try_from_syllables(test_word().0).unwrap();
+        assert_eq!(word, test_word());
+    }
+
+    #[test]
+    fn test_try_from_syllables_monosyllable() {
+        let syl = mk_syl(vec![], phon("ɑ"), vec![], Stress::Stressed);
+        let word = Word::try_from_syllables(vec![syl]).unwrap();
+        assert_eq!(word.0[0].stress, None);
+    }
+
+    #[test]
+    fn test_try_from_syllables_multiple_primary_stress() {
+        let word = Word::try_from_syllables(vec![
+            mk_syl(vec![], phon("ɑ"), vec![], Stress::Stressed),
+            mk_syl(vec![], phon("i"), vec![], Stress::Stressed),
+        ]);
+        assert!(word.is_err());
+    }
+
+    #[test]
+    fn test_try_from_syllables_empty() {
+        assert!(Word::try_from_syllables(vec![]).is_err());
+    }
+
+    #[test]
+    fn test_timed_phonemes() {
+        let timed = test_word().timed_phonemes(500.0);
+        assert_eq!(timed.len(), 7);
+        assert_eq!(timed[0].1, 0.0);
+        assert_eq!(timed[6].2, 500.0);
+        for pair in timed.windows(2) {
+            assert_eq!(pair[0].2, pair[1].1);
+        }
+        let sum: f64 = timed.iter().map(|(_, start, end)| end - start).sum();
+        assert!((sum - 500.0).abs() < 1e-9);
+        // the vowel ʌ (1.0) lasts twice as long as the stop p (0.5)
+        let stop = timed[0].2 - timed[0].1;
+        let vowel = timed[1].2 - timed[1].1;
+        assert!((vowel - 2.0 * stop).abs() < 1e-9);
+        assert!(Word::from(vec![]).timed_phonemes(500.0).is_empty());
+    }
+
+    #[test]
+    fn test_mora_count() {
+        // pʌm (heavy) + kɪn (heavy)
+        assert_eq!(test_word().mora_count(), 4);
+        // bə (light) + na͡ɪ (heavy)
+        assert_eq!(genam::word("bə.ˈna͡ɪ").unwrap().mora_count(), 3);
+    }
+
     #[test]
     fn test_phonemes() {
         let test_word = test_word();
@@ -154,6 +633,13 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_iter_phonemes() {
+        let test_word = test_word();
+        let iterated: Vec<Phoneme> = test_word.iter_phonemes().collect();
+        assert_eq!(iterated, test_word.phonemes());
+    }
+
     #[test]
     fn test_stresses() {
         let test_word = test_word();
@@ -163,6 +649,202 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_primary_stress_index() {
+        let test_word = test_word();
+        assert_eq!(test_word.primary_stress_index(), Some(0));
+    }
+
+    #[test]
+    fn test_has_stress() {
+        assert!(test_word().has_stress());
+        let unstressed = Word::from(vec![
+            mk_syl(vec![], phon("ə"), vec![], Stress::Unstressed),
+            mk_syl(vec![], phon("ɪ"), vec![], Stress::ReducedStress),
+        ]);
+        assert!(!unstressed.has_stress());
+        assert_eq!(unstressed.primary_stress_index(), None);
+    }
+
+    #[test]
+    fn test_stress_pattern() {
+        let test_word = test_word();
+        assert_eq!(test_word.stress_pattern(), "13");
+        assert_eq!(test_word.binary_stress_pattern(), "Su");
+    }
+
+    #[test]
+    fn test_stress_pattern_monosyllable() {
+        let word = Word::new(&[Syllable::new(&[], phon("ɑ"), &[], None)]);
+        assert_eq!(word.stress_pattern(), "1");
+        assert_eq!(word.binary_stress_pattern(), "S");
+    }
+
+    #[test]
+    fn test_symbols_with_boundaries() {
+        let test_word = Word::from(vec![
+            mk_syl(
+                phons(vec![]),
+                phon("æ"),
+                phons(vec!["k"]),
+                Stress::Stressed,
+            ),
+            mk_syl(phons(vec!["ɹ"]), phon("ə"), vec![], Stress::Unstressed),
+            mk_syl(
+                phons(vec!["b"]),
+                phon("æ"),
+                phons(vec!["t"]),
+                Stress::SecondaryStress,
+            ),
+        ]);
+        assert_eq!(test_word.symbols_with_boundaries(), "ˈæk.ɹə.ˌbæt");
+        assert_eq!(test_word.symbols(), "ˈæk.ɹəˌbæt");
+    }
+
+    #[test]
+    fn test_symbols_with_boundaries_reduced_stress() {
+        let word = genam::word("ˈti4tə").unwrap();
+        assert_eq!(word.symbols_with_boundaries(), "ˈti.4tə");
+        assert_eq!(genam::word(&word.symbols_with_boundaries()).unwrap(), word);
+        assert_eq!(word.symbols(), "ˈti4tə");
+    }
+
+    #[test]
+    fn test_are_homophones() {
+        // "write" and "right", with a stress mark, an alternative spelling of
+        // 'ɹ', and a diphthong written without a tie bar
+        let write = genam::word("ˈraɪt").unwrap();
+        let right = genam::word("ɹa͡ɪt").unwrap();
+        assert!(are_homophones(&write, &right));
+        assert_eq!(near_homophone_score(&write, &right), 1.0);
+        // "ride" differs only in the voicing of its final consonant
+        let ride = genam::word("ɹa͡ɪd").unwrap();
+        assert!(!are_homophones(&right, &ride));
+        assert!(near_homophone_score(&right, &ride) < 1.0);
+        // syllabification and stress are ignored
+        assert!(are_homophones(
+            &genam::word("ˈæk.ɹə").unwrap(),
+            &genam::word("æˈkɹə").unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_near_homophones() {
+        let affect = genam::word("əˈfɛkt").unwrap();
+        let effect = genam::word("ɪˈfɛkt").unwrap();
+        assert!(!are_homophones(&affect, &effect));
+        let score = near_homophone_score(&affect, &effect);
+        assert!(score > 0.7 && score < 1.0);
+        let dog = genam::word("dɔɡ").unwrap();
+        assert!(near_homophone_score(&affect, &dog) < score);
+    }
+
+    #[test]
+    fn test_with_primary_stress() {
+        let shifted = test_word().with_primary_stress(1).unwrap();
+        assert_eq!(
+            shifted.stresses(),
+            vec![Stress::SecondaryStress, Stress::Stressed]
+        );
+    }
+
+    #[test]
+    fn test_with_primary_stress_out_of_range() {
+        assert!(test_word().with_primary_stress(2).is_err());
+    }
+
+    #[test]
+    fn test_without_stress() {
+        let a = genam::word("ˈæk.ɹə.ˌbæt").unwrap();
+        let b = genam::word("æk.ˈɹə.bæt").unwrap();
+        assert_ne!(a, b);
+        let (a, b) = (a.without_stress(), b.without_stress());
+        assert_eq!(a, b);
+        assert!(a.iter().all(|syl| syl.stress.is_none()));
+    }
+
+    #[test]
+    fn test_concat() {
+        let word = |desc| genam::word(desc).unwrap();
+        let blackbird = word("blæk").concat(word("bɝd"));
+        assert_eq!(blackbird, word("ˈblæk.ˌbɝd"));
+        assert_eq!(
+            blackbird.stresses(),
+            vec![Stress::Stressed, Stress::SecondaryStress]
+        );
+        let raw = word("blæk").concat_raw(word("bɝd"));
+        assert_eq!(raw.syllables().len(), 2);
+        assert!(raw.iter().all(|syl| syl.stress.is_none()));
+        assert_eq!(
+            word("ˈbæ.kə").concat(word("ˈpɑ.kət")),
+            word("ˈbæ.kə.ˌpɑ.kət")
+        );
+    }
+
+    #[test]
+    fn test_has_stress_clash() {
+        let word = |desc| genam::word(desc).unwrap();
+        assert!(!word("ˈæk.ɹə.ˌbæt").has_stress_clash());
+        assert!(word("ˌæk.ˈɹə.bæt").has_stress_clash());
+        assert!(word("ˈæk.ˌɹə.bæt").has_stress_clash());
+        assert!(!word("bæt").has_stress_clash());
+        assert!(word("blæk").concat(word("bɝd")).has_stress_clash());
+    }
+
+    #[test]
+    fn test_split_disegments() {
+        let w = genam::word("ˈt͡ʃa͡ɪ.nə").unwrap().split_disegments();
+        let syls = w.syllables();
+        assert_eq!(syls.len(), 2);
+        assert_eq!(syls[0].onset, vec![phon("t"), phon("ʃ")]);
+        assert_eq!(syls[0].nucleus.symbol(), "a");
+        assert_eq!(syls[0].coda.len(), 1);
+        assert_eq!(syls[0].coda[0].symbol(), "ɪ");
+        assert_eq!(syls[0].stress, Some(Stress::Stressed));
+        assert_eq!(syls[1], genam::word("ˈt͡ʃa͡ɪ.nə").unwrap().syllables()[1]);
+        assert!(w.iter_phonemes().all(|p| p.is_monosegment()));
+    }
+
+    #[test]
+    fn test_feet() {
+        let test_word = test_word();
+        assert_eq!(test_word.feet(), (vec![Foot::Trochee], vec![]));
+
+        let word = Word::new(&[Syllable::new(&[], phon("ɑ"), &[], None)]);
+        assert_eq!(word.feet(), (vec![], vec![BinaryStress::Stressed]));
+    }
+
+    #[test]
+    fn test_iter() {
+        let test_word = test_word();
+        let nuclei: Vec<Phoneme> =
+            test_word.iter().map(|syl| syl.nucleus).collect();
+        assert_eq!(nuclei, phons(vec!["ʌ", "ɪ"]));
+
+        let mut count = 0;
+        for syl in &test_word {
+            assert!(syl.stress.is_some());
+            count += 1;
+        }
+        assert_eq!(count, 2);
+        assert_eq!(test_word, self::test_word());
+    }
+
+    #[test]
+    fn test_symbols_round_trip() {
+        // secondary stress marks must survive parsing and rendering. Input
+        // must use canonical symbols ("ɹ", not "r") to round trip exactly.
+        for desc in &[
+            "ˈpɹɛz.ənˌte͡ɪ.ʃən",
+            "ˌæk.ɹəˈbæt",
+            "ˈhɛ.lo͡ʊ",
+            "əˈla͡ʊ",
+            "ˈti4tə",
+        ] {
+            assert_eq!(genam::word(desc).unwrap().symbols(), *desc);
+        }
+    }
+
     #[test]
     fn test_symbols() {
         let test_word = test_word();